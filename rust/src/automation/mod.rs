@@ -0,0 +1,18 @@
+//! Declarative automation for browser automation.
+//!
+//! This module provides a way to drive a browser session from a declarative
+//! list of steps (e.g. parsed from JSON/YAML) instead of hand-written Rust,
+//! plus structured progress events for rendering or reporting.
+
+pub mod runner;
+pub mod script;
+pub mod watch;
+
+pub use runner::{
+    run_steps, Assertion, RunnerEvent, Step, StepOutcome, TestAdapter,
+};
+pub use script::{run_script, Feedback, ScriptStep};
+pub use watch::{
+    load_script, resolve_script_path, run_once, run_once_recorded, watch_script, ResolutionResult,
+    RunSummary, WatchError, WatchRunner,
+};
@@ -0,0 +1,389 @@
+//! Declarative step runner for browser automation.
+//!
+//! This module executes a list of [`Step`]s against an [`EngineAdapter`],
+//! emitting structured [`RunnerEvent`]s as it goes so that callers can render
+//! progress or persist a report without writing Rust for every flow.
+
+use crate::browser::navigation_ops::{goto, NavigationOptions};
+use crate::core::engine::{EngineAdapter, EngineError};
+use crate::elements::content::{
+    get_attribute, input_value, is_element_empty, text_content, truncate_for_preview,
+};
+use crate::elements::ElementLogInfo;
+use crate::interactions::click::{click_element, ClickOptions};
+use crate::interactions::scroll::{scroll_into_view, ScrollOptions};
+use crate::utilities::wait::wait;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+/// A single declarative automation step.
+///
+/// Steps are tagged by `op` so they can be parsed directly from JSON or YAML.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Step {
+    /// Navigate to a URL.
+    Goto {
+        /// The URL to navigate to.
+        url: String,
+    },
+    /// Click an element.
+    ElementClick {
+        /// The selector for the element to click.
+        selector: String,
+    },
+    /// Wait for a selector to appear.
+    ElementWait {
+        /// The selector to wait for.
+        selector: String,
+        /// Timeout in milliseconds.
+        timeout_ms: u64,
+    },
+    /// Focus an element.
+    ElementFocus {
+        /// The selector for the element to focus.
+        selector: String,
+    },
+    /// Scroll an element into view.
+    ElementScrollTo {
+        /// The selector for the element to scroll to.
+        selector: String,
+    },
+    /// Wait for a fixed duration.
+    Wait {
+        /// How long to wait, in milliseconds.
+        ms: u64,
+    },
+    /// Assert a condition about an element.
+    Assert {
+        /// The assertion to check.
+        assertion: Assertion,
+    },
+}
+
+/// An assertion that can be made about an element.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Assertion {
+    /// The element's text content equals the given value.
+    TextEquals {
+        /// The selector for the element.
+        selector: String,
+        /// The expected text content.
+        value: String,
+    },
+    /// The element's input value equals the given value.
+    ValueEquals {
+        /// The selector for the element.
+        selector: String,
+        /// The expected input value.
+        value: String,
+    },
+    /// The element's attribute equals the given value.
+    AttributeEquals {
+        /// The selector for the element.
+        selector: String,
+        /// The attribute name.
+        attribute: String,
+        /// The expected attribute value.
+        value: String,
+    },
+    /// The element is empty (for input-like elements).
+    IsEmpty {
+        /// The selector for the element.
+        selector: String,
+    },
+}
+
+/// The outcome of running a single step.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepOutcome {
+    /// The step completed successfully.
+    Ok,
+    /// The step was skipped.
+    Skipped,
+    /// The step failed, with a diagnostic reason.
+    Failed(String),
+}
+
+/// A structured progress event emitted while running steps.
+///
+/// Events are delivered over an `mpsc` channel so a caller can render
+/// progress live or persist a report after the run completes.
+#[derive(Debug, Clone)]
+pub enum RunnerEvent {
+    /// The run has started, with the total number of steps.
+    Plan {
+        /// Total number of steps in the run.
+        total: usize,
+    },
+    /// A step has started.
+    StepStart {
+        /// The step's index.
+        index: usize,
+        /// A short human-readable name for the step.
+        name: String,
+    },
+    /// A step has finished.
+    StepResult {
+        /// The step's index.
+        index: usize,
+        /// How long the step took.
+        duration: Duration,
+        /// The outcome of the step.
+        outcome: StepOutcome,
+    },
+}
+
+/// High-level element operations used by the step runner.
+///
+/// This wraps an [`EngineAdapter`] with the operations a declarative step
+/// needs, so [`run_steps`] does not need to know about lower-level engine
+/// details. It is blanket-implemented for every [`EngineAdapter`].
+#[async_trait]
+pub trait TestAdapter: EngineAdapter {
+    /// Click an element.
+    async fn element_click(&self, selector: &str) -> Result<(), EngineError> {
+        click_element(self, selector, &ClickOptions::default()).await?;
+        Ok(())
+    }
+
+    /// Wait for a selector to appear.
+    async fn element_wait(&self, selector: &str, timeout_ms: u64) -> Result<(), EngineError> {
+        self.wait_for_selector(selector, timeout_ms).await
+    }
+
+    /// Focus an element by clicking it without verification.
+    async fn element_focus(&self, selector: &str) -> Result<(), EngineError> {
+        let options = ClickOptions {
+            scroll_into_view: false,
+            verify: false,
+            ..Default::default()
+        };
+        click_element(self, selector, &options).await?;
+        Ok(())
+    }
+
+    /// Scroll an element into view.
+    async fn element_scroll_to(&self, selector: &str) -> Result<(), EngineError> {
+        scroll_into_view(self, selector, &ScrollOptions::default()).await?;
+        Ok(())
+    }
+}
+
+impl<T: EngineAdapter + ?Sized> TestAdapter for T {}
+
+/// Maximum length of [`ElementLogInfo::text_preview`] captured for a failed
+/// step, long enough to identify the element without flooding the log with
+/// an entire DOM subtree's text.
+const FAILURE_TEXT_PREVIEW_LEN: usize = 80;
+
+/// Attributes checked, in order, when identifying the element a failed step
+/// targeted; only those present and non-empty are kept.
+const FAILURE_IDENTIFYING_ATTRIBUTES: &[&str] = &["id", "class", "data-qa", "aria-label"];
+
+/// Capture diagnostic information about the element a failed step targeted.
+async fn capture_failure_info(adapter: &dyn EngineAdapter, selector: &str) -> Option<ElementLogInfo> {
+    let info = adapter.query_selector(selector).await.ok()??;
+
+    let mut attributes = Vec::new();
+    for &attribute in FAILURE_IDENTIFYING_ATTRIBUTES {
+        if let Ok(Some(value)) = get_attribute(adapter, selector, attribute).await {
+            if !value.is_empty() {
+                attributes.push((attribute.to_string(), value));
+            }
+        }
+    }
+
+    Some(ElementLogInfo {
+        tag_name: info.tag_name,
+        text_preview: truncate_for_preview(
+            &info.text_content.unwrap_or_default(),
+            FAILURE_TEXT_PREVIEW_LEN,
+        ),
+        attributes,
+    })
+}
+
+/// Run a condition, turning it into a [`StepOutcome`].
+async fn run_assertion(
+    adapter: &dyn EngineAdapter,
+    assertion: &Assertion,
+) -> Result<StepOutcome, EngineError> {
+    let ok = match assertion {
+        Assertion::TextEquals { selector, value } => {
+            text_content(adapter, selector).await?.as_deref() == Some(value.as_str())
+        }
+        Assertion::ValueEquals { selector, value } => {
+            input_value(adapter, selector).await?.as_deref() == Some(value.as_str())
+        }
+        Assertion::AttributeEquals {
+            selector,
+            attribute,
+            value,
+        } => get_attribute(adapter, selector, attribute).await?.as_deref() == Some(value.as_str()),
+        Assertion::IsEmpty { selector } => is_element_empty(adapter, selector).await?,
+    };
+
+    if ok {
+        Ok(StepOutcome::Ok)
+    } else {
+        Ok(StepOutcome::Failed(format!("assertion failed: {:?}", assertion)))
+    }
+}
+
+/// A short human-readable name for a step, used in [`RunnerEvent::StepStart`].
+pub(crate) fn step_name(step: &Step) -> String {
+    match step {
+        Step::Goto { url } => format!("goto {url}"),
+        Step::ElementClick { selector } => format!("click {selector}"),
+        Step::ElementWait { selector, .. } => format!("wait_for {selector}"),
+        Step::ElementFocus { selector } => format!("focus {selector}"),
+        Step::ElementScrollTo { selector } => format!("scroll_to {selector}"),
+        Step::Wait { ms } => format!("sleep {ms}ms"),
+        Step::Assert { .. } => "assert".to_string(),
+    }
+}
+
+/// Run a declarative list of steps against an [`EngineAdapter`].
+///
+/// Progress is reported on `events` as each step starts and finishes. The
+/// returned vector holds one [`StepOutcome`] per step, in order.
+///
+/// # Arguments
+///
+/// * `adapter` - The engine adapter to drive
+/// * `steps` - The steps to execute, in order
+/// * `events` - Channel on which structured progress events are sent
+///
+/// # Returns
+///
+/// One outcome per step, in the same order as `steps`
+pub async fn run_steps(
+    adapter: &dyn EngineAdapter,
+    steps: &[Step],
+    events: mpsc::Sender<RunnerEvent>,
+) -> Vec<StepOutcome> {
+    let _ = events.send(RunnerEvent::Plan { total: steps.len() }).await;
+
+    let mut outcomes = Vec::with_capacity(steps.len());
+
+    for (index, step) in steps.iter().enumerate() {
+        let _ = events
+            .send(RunnerEvent::StepStart {
+                index,
+                name: step_name(step),
+            })
+            .await;
+
+        let start = Instant::now();
+        let outcome = run_step(adapter, step).await;
+        let duration = start.elapsed();
+
+        let _ = events
+            .send(RunnerEvent::StepResult {
+                index,
+                duration,
+                outcome: outcome.clone(),
+            })
+            .await;
+
+        outcomes.push(outcome);
+    }
+
+    outcomes
+}
+
+/// Run a single step, converting engine errors into a [`StepOutcome::Failed`]
+/// that includes diagnostic info about the targeted element when available.
+async fn run_step(adapter: &dyn EngineAdapter, step: &Step) -> StepOutcome {
+    let result: Result<(), EngineError> = match step {
+        Step::Goto { url } => goto(adapter, url, &NavigationOptions::default())
+            .await
+            .map(|_| ()),
+        Step::ElementClick { selector } => adapter.element_click(selector).await,
+        Step::ElementWait {
+            selector,
+            timeout_ms,
+        } => adapter.element_wait(selector, *timeout_ms).await,
+        Step::ElementFocus { selector } => adapter.element_focus(selector).await,
+        Step::ElementScrollTo { selector } => adapter.element_scroll_to(selector).await,
+        Step::Wait { ms } => {
+            wait(Duration::from_millis(*ms), None).await;
+            Ok(())
+        }
+        Step::Assert { assertion } => {
+            return match run_assertion(adapter, assertion).await {
+                Ok(outcome) => outcome,
+                Err(e) => StepOutcome::Failed(e.to_string()),
+            };
+        }
+    };
+
+    match result {
+        Ok(()) => StepOutcome::Ok,
+        Err(e) => {
+            let selector = step_selector(step);
+            let info = match selector {
+                Some(selector) => capture_failure_info(adapter, selector).await,
+                None => None,
+            };
+            match info {
+                Some(info) => StepOutcome::Failed(format!("{e}: {info}")),
+                None => StepOutcome::Failed(e.to_string()),
+            }
+        }
+    }
+}
+
+/// Extract the selector a step targets, if any, for diagnostics.
+fn step_selector(step: &Step) -> Option<&str> {
+    match step {
+        Step::ElementClick { selector }
+        | Step::ElementWait { selector, .. }
+        | Step::ElementFocus { selector }
+        | Step::ElementScrollTo { selector } => Some(selector),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_deserializes_from_json() {
+        let json = r#"{"op":"goto","url":"https://example.com"}"#;
+        let step: Step = serde_json::from_str(json).unwrap();
+        assert!(matches!(step, Step::Goto { url } if url == "https://example.com"));
+    }
+
+    #[test]
+    fn assertion_deserializes_from_json() {
+        let json = r##"{"kind":"text_equals","selector":"#title","value":"Hello"}"##;
+        let assertion: Assertion = serde_json::from_str(json).unwrap();
+        assert!(matches!(assertion, Assertion::TextEquals { .. }));
+    }
+
+    #[test]
+    fn step_name_describes_goto() {
+        let step = Step::Goto {
+            url: "https://example.com".to_string(),
+        };
+        assert_eq!(step_name(&step), "goto https://example.com");
+    }
+
+    #[test]
+    fn step_selector_extracts_target() {
+        let step = Step::ElementClick {
+            selector: "#submit".to_string(),
+        };
+        assert_eq!(step_selector(&step), Some("#submit"));
+
+        let step = Step::Wait { ms: 100 };
+        assert_eq!(step_selector(&step), None);
+    }
+}
@@ -0,0 +1,323 @@
+//! Declarative JSON automation-script runner.
+//!
+//! Pairs with [`crate::automation::runner`], but targets a different use
+//! case: reusable automation recipes that are stored as standalone JSON
+//! files and need to be loaded and run without recompiling, optionally
+//! cancelled mid-flight. Each [`ScriptStep`] is tagged by `op` (`goto`,
+//! `click`, `wait_for`, `focus`, `scroll_to`, `sleep`, `assert`,
+//! `assert_text`) and every selector is resolved through
+//! [`normalize_selector`] before use, so `:text("...")`, XPath, and plain
+//! CSS selectors all work from the same recipe.
+
+use crate::browser::navigation_ops::{goto, NavigationOptions};
+use crate::core::engine::EngineAdapter;
+use crate::elements::content::text_content;
+use crate::elements::selectors::{
+    build_text_selector, normalize_selector, ParsedSelector, TextMatchMode,
+};
+use crate::elements::visibility::count;
+use crate::interactions::click::{click_element, ClickOptions};
+use crate::interactions::scroll::{scroll_into_view, ScrollOptions};
+use crate::utilities::wait::{wait, wait_with_cancel};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+/// A single step in a declarative automation recipe.
+///
+/// Steps are tagged by `op` so a recipe can be written and stored as plain
+/// JSON, e.g. `{"op":"click","selector":"#submit"}`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum ScriptStep {
+    /// Navigate to a URL.
+    Goto {
+        /// The URL to navigate to.
+        url: String,
+    },
+    /// Click an element.
+    Click {
+        /// The selector for the element to click.
+        selector: String,
+    },
+    /// Wait for a selector to appear.
+    WaitFor {
+        /// The selector to wait for.
+        selector: String,
+        /// Timeout in milliseconds.
+        timeout_ms: u64,
+    },
+    /// Focus an element.
+    Focus {
+        /// The selector for the element to focus.
+        selector: String,
+    },
+    /// Scroll an element into view.
+    ScrollTo {
+        /// The selector for the element to scroll to.
+        selector: String,
+    },
+    /// Sleep for a fixed duration, abortable via a [`CancellationToken`].
+    Sleep {
+        /// How long to sleep, in milliseconds.
+        ms: u64,
+    },
+    /// Assert that an element does (or does not) exist.
+    Assert {
+        /// The selector to check.
+        selector: String,
+        /// Whether the element is expected to exist.
+        exists: bool,
+    },
+    /// Assert that an element's text content equals a value.
+    AssertText {
+        /// The selector for the element.
+        selector: String,
+        /// The expected text content.
+        equals: String,
+    },
+}
+
+/// The outcome of running a single [`ScriptStep`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Feedback {
+    /// Whether the step succeeded.
+    pub success: bool,
+    /// A diagnostic message, present on failure (and optionally on success).
+    pub message: Option<String>,
+}
+
+impl Feedback {
+    /// A successful step with no extra detail.
+    pub fn ok() -> Self {
+        Self {
+            success: true,
+            message: None,
+        }
+    }
+
+    /// A failed step with a diagnostic message.
+    pub fn failed(message: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            message: Some(message.into()),
+        }
+    }
+}
+
+/// Resolve a recipe selector to the concrete string an [`EngineAdapter`]
+/// understands, passing it through [`normalize_selector`] first so
+/// `:text("...")`, XPath, and plain CSS selectors are all usable
+/// interchangeably in a script.
+fn resolve_selector(selector: &str) -> String {
+    match normalize_selector(selector) {
+        ParsedSelector::Css(css) => css,
+        ParsedSelector::XPath(xpath) => xpath,
+        ParsedSelector::Text {
+            text,
+            element,
+            match_mode,
+            case_insensitive,
+        } => build_text_selector(&text, element.as_deref(), match_mode, case_insensitive),
+    }
+}
+
+/// Run a declarative JSON automation recipe against an [`EngineAdapter`].
+///
+/// Unlike [`crate::automation::runner::run_steps`], this returns a flat
+/// [`Vec<Feedback>`] instead of streaming events, and accepts an optional
+/// [`CancellationToken`] so a running recipe can be aborted mid-flight
+/// (checked before each step, and used to make [`ScriptStep::Sleep`]
+/// abortable via [`wait_with_cancel`]). If cancelled, the remaining steps
+/// are reported as failed rather than silently omitted, so the feedback
+/// vector always has one entry per step.
+pub async fn run_script(
+    adapter: &dyn EngineAdapter,
+    steps: &[ScriptStep],
+    cancel_token: Option<&CancellationToken>,
+) -> Vec<Feedback> {
+    let mut feedback = Vec::with_capacity(steps.len());
+
+    for step in steps {
+        if cancel_token.is_some_and(CancellationToken::is_cancelled) {
+            feedback.push(Feedback::failed("cancelled"));
+            continue;
+        }
+
+        feedback.push(run_step(adapter, step, cancel_token).await);
+    }
+
+    feedback
+}
+
+/// Run a single [`ScriptStep`], converting engine errors into a failed
+/// [`Feedback`].
+async fn run_step(
+    adapter: &dyn EngineAdapter,
+    step: &ScriptStep,
+    cancel_token: Option<&CancellationToken>,
+) -> Feedback {
+    match step {
+        ScriptStep::Goto { url } => {
+            match goto(adapter, url, &NavigationOptions::default()).await {
+                Ok(_) => Feedback::ok(),
+                Err(e) => Feedback::failed(e.to_string()),
+            }
+        }
+        ScriptStep::Click { selector } => {
+            let selector = resolve_selector(selector);
+            match click_element(adapter, &selector, &ClickOptions::default()).await {
+                Ok(_) => Feedback::ok(),
+                Err(e) => Feedback::failed(e.to_string()),
+            }
+        }
+        ScriptStep::WaitFor {
+            selector,
+            timeout_ms,
+        } => {
+            let selector = resolve_selector(selector);
+            match adapter.wait_for_selector(&selector, *timeout_ms).await {
+                Ok(()) => Feedback::ok(),
+                Err(e) => Feedback::failed(e.to_string()),
+            }
+        }
+        ScriptStep::Focus { selector } => {
+            let selector = resolve_selector(selector);
+            let options = ClickOptions {
+                scroll_into_view: false,
+                verify: false,
+                ..Default::default()
+            };
+            match click_element(adapter, &selector, &options).await {
+                Ok(_) => Feedback::ok(),
+                Err(e) => Feedback::failed(e.to_string()),
+            }
+        }
+        ScriptStep::ScrollTo { selector } => {
+            let selector = resolve_selector(selector);
+            match scroll_into_view(adapter, &selector, &ScrollOptions::default()).await {
+                Ok(_) => Feedback::ok(),
+                Err(e) => Feedback::failed(e.to_string()),
+            }
+        }
+        ScriptStep::Sleep { ms } => {
+            let duration = Duration::from_millis(*ms);
+            let result = match cancel_token {
+                Some(token) => wait_with_cancel(duration, token, Some("script sleep")).await,
+                None => wait(duration, Some("script sleep")).await,
+            };
+            if result.aborted {
+                Feedback::failed("cancelled")
+            } else {
+                Feedback::ok()
+            }
+        }
+        ScriptStep::Assert { selector, exists } => {
+            let resolved = resolve_selector(selector);
+            match count(adapter, &resolved).await {
+                Ok(n) => {
+                    let found = n > 0;
+                    if found == *exists {
+                        Feedback::ok()
+                    } else {
+                        Feedback::failed(format!(
+                            "expected {selector} to {}exist",
+                            if *exists { "" } else { "not " }
+                        ))
+                    }
+                }
+                Err(e) => Feedback::failed(e.to_string()),
+            }
+        }
+        ScriptStep::AssertText { selector, equals } => {
+            let resolved = resolve_selector(selector);
+            match text_content(adapter, &resolved).await {
+                Ok(actual) => {
+                    if actual.as_deref() == Some(equals.as_str()) {
+                        Feedback::ok()
+                    } else {
+                        Feedback::failed(format!(
+                            "expected {selector} text to equal {equals:?}, got {actual:?}"
+                        ))
+                    }
+                }
+                Err(e) => Feedback::failed(e.to_string()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn script_step_deserializes_from_json() {
+        let json = r#"{"op":"goto","url":"https://example.com"}"#;
+        let step: ScriptStep = serde_json::from_str(json).unwrap();
+        assert!(matches!(step, ScriptStep::Goto { url } if url == "https://example.com"));
+    }
+
+    #[test]
+    fn script_step_deserializes_assert() {
+        let json = r##"{"op":"assert","selector":"#title","exists":true}"##;
+        let step: ScriptStep = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            step,
+            ScriptStep::Assert { exists: true, .. }
+        ));
+    }
+
+    #[test]
+    fn script_step_deserializes_assert_text() {
+        let json = r##"{"op":"assert_text","selector":"#title","equals":"Hello"}"##;
+        let step: ScriptStep = serde_json::from_str(json).unwrap();
+        assert!(matches!(step, ScriptStep::AssertText { .. }));
+    }
+
+    #[test]
+    fn resolve_selector_passes_css_through() {
+        assert_eq!(resolve_selector("button.submit"), "button.submit");
+    }
+
+    #[test]
+    fn resolve_selector_converts_text_selector_to_xpath() {
+        let resolved = resolve_selector(":text(\"Submit\")");
+        assert!(resolved.contains("contains(text()"));
+        assert!(resolved.contains("Submit"));
+    }
+
+    #[test]
+    fn resolve_selector_passes_xpath_through() {
+        assert_eq!(resolve_selector("//button"), "//button");
+    }
+
+    #[test]
+    fn feedback_ok_has_no_message() {
+        let feedback = Feedback::ok();
+        assert!(feedback.success);
+        assert!(feedback.message.is_none());
+    }
+
+    #[test]
+    fn feedback_failed_carries_message() {
+        let feedback = Feedback::failed("boom");
+        assert!(!feedback.success);
+        assert_eq!(feedback.message.as_deref(), Some("boom"));
+    }
+
+    #[tokio::test]
+    async fn run_script_skips_remaining_steps_once_cancelled() {
+        let adapter = crate::browser::webdriver::WebDriverAdapter::new(
+            "http://localhost:4444".parse().unwrap(),
+        );
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let steps = vec![ScriptStep::Sleep { ms: 0 }, ScriptStep::Sleep { ms: 0 }];
+        let feedback = run_script(&adapter, &steps, Some(&token)).await;
+
+        assert_eq!(feedback.len(), 2);
+        assert!(feedback.iter().all(|f| !f.success));
+    }
+}
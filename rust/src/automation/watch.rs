@@ -0,0 +1,497 @@
+//! File-watching re-run loop for declarative automation scripts.
+//!
+//! Pairs with [`crate::automation::runner`]: loads a JSON [`Step`] script
+//! from disk, runs it against a live [`EngineAdapter`], and re-runs it
+//! whenever the script file changes, so an automation can be iterated on
+//! without restarting the browser session between edits.
+
+use crate::automation::runner::{run_steps, step_name, RunnerEvent, Step, StepOutcome};
+use crate::browser::navigation_ops::{goto, NavigationOptions};
+use crate::core::engine::EngineAdapter;
+use crate::reporting::{CaseStatus, TestCase, TestSuite};
+use notify::{RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::mpsc;
+
+/// How long to wait after the last filesystem event before re-running, so a
+/// burst of writes from an editor only triggers a single run.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Errors that can occur while loading or watching an automation script.
+#[derive(Debug, Error)]
+pub enum WatchError {
+    /// The script file could not be read.
+    #[error("failed to read script {path}: {source}")]
+    Read {
+        /// The script path that failed to read.
+        path: PathBuf,
+        /// The underlying I/O error.
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The script file was not valid JSON steps.
+    #[error("failed to parse script {path}: {source}")]
+    Parse {
+        /// The script path that failed to parse.
+        path: PathBuf,
+        /// The underlying JSON error.
+        #[source]
+        source: serde_json::Error,
+    },
+
+    /// The filesystem watcher could not be started.
+    #[error("failed to watch {path}: {source}")]
+    Watch {
+        /// The path that could not be watched.
+        path: PathBuf,
+        /// The underlying notify error.
+        #[source]
+        source: notify::Error,
+    },
+}
+
+/// A concise summary of one script run.
+#[derive(Debug, Clone, Default)]
+pub struct RunSummary {
+    /// Number of steps that completed successfully.
+    pub passed: usize,
+    /// Number of steps that failed.
+    pub failed: usize,
+    /// The URL reported by the `goto(start_url)` reset that begins the run
+    /// (its [`NavigationResult`](crate::browser::NavigationResult)
+    /// `actual_url`), whether or not that navigation was verified. URL
+    /// changes made by later steps are not reflected; `None` if the reset
+    /// navigation returned an error.
+    pub final_url: Option<String>,
+}
+
+impl std::fmt::Display for RunSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, final url: {}",
+            self.passed,
+            self.failed,
+            self.final_url.as_deref().unwrap_or("<unknown>")
+        )
+    }
+}
+
+/// Resolve a (possibly relative) script path against a captured base
+/// directory rather than the process's current directory.
+///
+/// Callers should capture the process's initial working directory once at
+/// startup and pass it here, so that a `chdir` performed mid-run (e.g. by a
+/// step) doesn't change how the next watch iteration resolves the script.
+pub fn resolve_script_path(base_dir: &Path, script: &Path) -> PathBuf {
+    if script.is_absolute() {
+        script.to_path_buf()
+    } else {
+        base_dir.join(script)
+    }
+}
+
+/// Load a JSON automation script (a list of [`Step`]s) from disk.
+pub fn load_script(path: &Path) -> Result<Vec<Step>, WatchError> {
+    let contents = std::fs::read_to_string(path).map_err(|source| WatchError::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    serde_json::from_str(&contents).map_err(|source| WatchError::Parse {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Run a script once against `adapter`: reset to `start_url`, execute every
+/// step, and summarize the outcome.
+pub async fn run_once(adapter: &dyn EngineAdapter, steps: &[Step], start_url: &str) -> RunSummary {
+    let navigation = goto(adapter, start_url, &NavigationOptions::default())
+        .await
+        .ok();
+
+    let (tx, mut rx) = mpsc::channel::<RunnerEvent>(steps.len().max(1) + 1);
+    let events = tokio::spawn(async move {
+        let mut outcomes = Vec::new();
+        while let Some(event) = rx.recv().await {
+            if let RunnerEvent::StepResult { outcome, .. } = event {
+                outcomes.push(outcome);
+            }
+        }
+        outcomes
+    });
+
+    run_steps(adapter, steps, tx).await;
+    let outcomes = events.await.unwrap_or_default();
+
+    let passed = outcomes
+        .iter()
+        .filter(|o| matches!(o, StepOutcome::Ok))
+        .count();
+    let failed = outcomes.len() - passed;
+
+    RunSummary {
+        passed,
+        failed,
+        final_url: navigation.and_then(|n| n.actual_url),
+    }
+}
+
+/// Watch `script_path` for changes, re-running it against `adapter` on every
+/// change (debounced) until the process is interrupted.
+///
+/// The browser session backing `adapter` is kept alive across runs; only the
+/// page is reset, via `goto(start_url)`, before each re-run. Any in-flight
+/// run is cancelled when a new change arrives.
+pub async fn watch_script(
+    adapter: &dyn EngineAdapter,
+    script_path: &Path,
+    start_url: &str,
+) -> Result<(), WatchError> {
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut watcher =
+        notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            let _ = tx.send(res);
+        })
+        .map_err(|source| WatchError::Watch {
+            path: script_path.to_path_buf(),
+            source,
+        })?;
+    watcher
+        .watch(script_path, RecursiveMode::NonRecursive)
+        .map_err(|source| WatchError::Watch {
+            path: script_path.to_path_buf(),
+            source,
+        })?;
+
+    // Forward raw filesystem events to the async side on a tokio channel, so
+    // the debounce wait and re-run both happen on the async runtime while
+    // the blocking std::sync::mpsc receiver stays on its own thread for the
+    // lifetime of the watch.
+    let (trigger_tx, mut trigger_rx) = mpsc::channel::<()>(1);
+    std::thread::spawn(move || {
+        while rx.recv().is_ok() {
+            // A full channel means a run is already pending; that's fine,
+            // it still picks up this change once it wakes.
+            let _ = trigger_tx.try_send(());
+        }
+    });
+
+    loop {
+        // A change that arrives mid-run wins the race, dropping (and so
+        // cancelling) the in-flight run before the debounced re-run.
+        let change = tokio::select! {
+            _ = run_pending(adapter, script_path, start_url) => trigger_rx.recv().await,
+            change = trigger_rx.recv() => change,
+        };
+        if change.is_none() {
+            break;
+        }
+        tokio::time::sleep(DEBOUNCE).await;
+        while trigger_rx.try_recv().is_ok() {}
+    }
+
+    Ok(())
+}
+
+/// Reload and run the script, printing a one-line summary (or the load
+/// error) instead of propagating it, so one bad edit doesn't kill the
+/// watcher.
+async fn run_pending(adapter: &dyn EngineAdapter, script_path: &Path, start_url: &str) {
+    match load_script(script_path) {
+        Ok(steps) => {
+            let summary = run_once(adapter, &steps, start_url).await;
+            println!("[watch] {summary}");
+        }
+        Err(e) => {
+            eprintln!("[watch] {e}");
+        }
+    }
+}
+
+/// Run a script once against `adapter`, recording a [`TestSuite`] (one
+/// [`TestCase`] per step) instead of the plain [`RunSummary`] that
+/// [`run_once`] returns, so a watch cycle's result can be serialized
+/// through the same reporting pipeline (`to_junit_xml`/`to_ndjson`) as any
+/// other run.
+pub async fn run_once_recorded(
+    adapter: &dyn EngineAdapter,
+    steps: &[Step],
+    start_url: &str,
+    suite_name: impl Into<String>,
+) -> TestSuite {
+    let _ = goto(adapter, start_url, &NavigationOptions::default()).await;
+
+    let (tx, mut rx) = mpsc::channel::<RunnerEvent>(steps.len().max(1) + 1);
+    let names: Vec<String> = steps.iter().map(step_name).collect();
+    let events = tokio::spawn(async move {
+        let mut cases = Vec::new();
+        while let Some(event) = rx.recv().await {
+            if let RunnerEvent::StepResult {
+                index,
+                duration,
+                outcome,
+            } = event
+            {
+                let name = names.get(index).map(String::as_str).unwrap_or("step");
+                cases.push(TestCase::from_step_outcome(name, &outcome, duration));
+            }
+        }
+        cases
+    });
+
+    run_steps(adapter, steps, tx).await;
+    let cases = events.await.unwrap_or_default();
+
+    let mut suite = TestSuite::new(suite_name);
+    suite.cases = cases;
+    suite
+}
+
+/// The outcome of one [`WatchRunner`] cycle: which watched paths changed
+/// (empty on the initial run), and whether the flow actually re-ran or was
+/// skipped because none of the changed paths were relevant.
+///
+/// Named after the "resolve specifiers, run, or skip" cycle deno's test
+/// watcher reports per iteration.
+#[derive(Debug, Clone)]
+pub struct ResolutionResult {
+    /// Paths observed to have changed since the previous cycle (empty for
+    /// the initial run).
+    pub changed_paths: Vec<PathBuf>,
+    /// Whether the flow was re-run this cycle.
+    pub ran: bool,
+    /// The recorded suite, if the flow ran.
+    pub suite: Option<TestSuite>,
+}
+
+/// Drives repeated runs of one automation flow, re-executing it whenever a
+/// watched file changes.
+///
+/// Unlike [`watch_script`], a `WatchRunner` owns its [`EngineAdapter`] for
+/// its entire lifetime, so the browser session is launched once and stays
+/// warm across every cycle, and it watches every path the flow depends on
+/// (the script itself plus any referenced config/selector files), not just
+/// the script file.
+pub struct WatchRunner {
+    adapter: Box<dyn EngineAdapter>,
+    script_path: PathBuf,
+    watched_paths: Vec<PathBuf>,
+    start_url: String,
+}
+
+impl WatchRunner {
+    /// Create a runner that re-executes the JSON step script at
+    /// `script_path` against `adapter`, also watching `extra_paths` (e.g.
+    /// a shared selectors or config file the script references).
+    pub fn new(
+        adapter: Box<dyn EngineAdapter>,
+        script_path: PathBuf,
+        extra_paths: Vec<PathBuf>,
+        start_url: impl Into<String>,
+    ) -> Self {
+        let mut watched_paths = extra_paths;
+        watched_paths.push(script_path.clone());
+
+        Self {
+            adapter,
+            script_path,
+            watched_paths,
+            start_url: start_url.into(),
+        }
+    }
+
+    /// Every path this runner watches, including the script itself.
+    pub fn watched_paths(&self) -> &[PathBuf] {
+        &self.watched_paths
+    }
+
+    /// Run one cycle: reload and re-execute the script if `changed_paths`
+    /// is empty (the initial run) or includes one of [`Self::watched_paths`],
+    /// otherwise skip without touching the browser session.
+    pub async fn run_cycle(&self, changed_paths: Vec<PathBuf>) -> ResolutionResult {
+        let relevant =
+            changed_paths.is_empty() || changed_paths.iter().any(|p| self.watched_paths.contains(p));
+
+        if !relevant {
+            return ResolutionResult {
+                changed_paths,
+                ran: false,
+                suite: None,
+            };
+        }
+
+        let suite = match load_script(&self.script_path) {
+            Ok(steps) => {
+                run_once_recorded(
+                    self.adapter.as_ref(),
+                    &steps,
+                    &self.start_url,
+                    self.script_path.display().to_string(),
+                )
+                .await
+            }
+            Err(e) => {
+                let mut suite = TestSuite::new(self.script_path.display().to_string());
+                suite.cases.push(TestCase {
+                    name: "load script".to_string(),
+                    selector: None,
+                    status: CaseStatus::Failed(e.to_string()),
+                    duration: Duration::ZERO,
+                    attempts: None,
+                });
+                suite
+            }
+        };
+
+        ResolutionResult {
+            changed_paths,
+            ran: true,
+            suite: Some(suite),
+        }
+    }
+
+    /// Watch every path returned by [`Self::watched_paths`], running one
+    /// cycle immediately and then once per debounced burst of changes,
+    /// until the process is interrupted.
+    ///
+    /// `on_cycle` is invoked with each cycle's [`ResolutionResult`], so a
+    /// caller can print a summary or serialize the suite through the
+    /// reporting pipeline.
+    pub async fn watch(
+        &self,
+        mut on_cycle: impl FnMut(ResolutionResult),
+    ) -> Result<(), WatchError> {
+        let (raw_tx, raw_rx) = std::sync::mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            let _ = raw_tx.send(res);
+        })
+        .map_err(|source| WatchError::Watch {
+            path: self.script_path.clone(),
+            source,
+        })?;
+        for path in &self.watched_paths {
+            watcher
+                .watch(path, RecursiveMode::NonRecursive)
+                .map_err(|source| WatchError::Watch {
+                    path: path.clone(),
+                    source,
+                })?;
+        }
+
+        // Forward raw filesystem events to the async side, collecting which
+        // watched paths changed so a debounced burst reports all of them.
+        let (trigger_tx, mut trigger_rx) = mpsc::channel::<PathBuf>(16);
+        std::thread::spawn(move || {
+            while let Ok(Ok(event)) = raw_rx.recv() {
+                for path in event.paths {
+                    // A full channel just means a run is already pending;
+                    // it still picks up this change once it wakes.
+                    let _ = trigger_tx.try_send(path);
+                }
+            }
+        });
+
+        on_cycle(self.run_cycle(Vec::new()).await);
+
+        while let Some(first) = trigger_rx.recv().await {
+            let mut changed = vec![first];
+            tokio::time::sleep(DEBOUNCE).await;
+            while let Ok(path) = trigger_rx.try_recv() {
+                if !changed.contains(&path) {
+                    changed.push(path);
+                }
+            }
+
+            on_cycle(self.run_cycle(changed).await);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_script_path_joins_relative() {
+        let base = Path::new("/home/user/project");
+        let resolved = resolve_script_path(base, Path::new("scripts/login.json"));
+        assert_eq!(resolved, PathBuf::from("/home/user/project/scripts/login.json"));
+    }
+
+    #[test]
+    fn resolve_script_path_keeps_absolute() {
+        let base = Path::new("/home/user/project");
+        let resolved = resolve_script_path(base, Path::new("/tmp/login.json"));
+        assert_eq!(resolved, PathBuf::from("/tmp/login.json"));
+    }
+
+    #[test]
+    fn load_script_reports_missing_file() {
+        let result = load_script(Path::new("/nonexistent/script.json"));
+        assert!(matches!(result, Err(WatchError::Read { .. })));
+    }
+
+    #[test]
+    fn load_script_reports_invalid_json() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("browser_commander_watch_test_invalid.json");
+        std::fs::write(&path, "not json").unwrap();
+        let result = load_script(&path);
+        std::fs::remove_file(&path).ok();
+        assert!(matches!(result, Err(WatchError::Parse { .. })));
+    }
+
+    #[test]
+    fn run_summary_display_formats_counts() {
+        let summary = RunSummary {
+            passed: 3,
+            failed: 1,
+            final_url: Some("https://example.com".to_string()),
+        };
+        assert_eq!(
+            summary.to_string(),
+            "3 passed, 1 failed, final url: https://example.com"
+        );
+    }
+
+    fn test_runner() -> WatchRunner {
+        let adapter = crate::browser::webdriver::WebDriverAdapter::new(
+            "http://localhost:4444".parse().unwrap(),
+        );
+        WatchRunner::new(
+            Box::new(adapter),
+            PathBuf::from("/tmp/browser_commander_watch_test_script.json"),
+            vec![PathBuf::from("/tmp/browser_commander_watch_test_selectors.json")],
+            "about:blank",
+        )
+    }
+
+    #[test]
+    fn watch_runner_watches_script_and_extra_paths() {
+        let runner = test_runner();
+        assert_eq!(runner.watched_paths().len(), 2);
+        assert!(runner
+            .watched_paths()
+            .contains(&PathBuf::from("/tmp/browser_commander_watch_test_script.json")));
+        assert!(runner
+            .watched_paths()
+            .contains(&PathBuf::from("/tmp/browser_commander_watch_test_selectors.json")));
+    }
+
+    #[tokio::test]
+    async fn watch_runner_skips_cycle_for_irrelevant_change() {
+        let runner = test_runner();
+        let result = runner
+            .run_cycle(vec![PathBuf::from("/tmp/unrelated.json")])
+            .await;
+
+        assert!(!result.ran);
+        assert!(result.suite.is_none());
+    }
+}
@@ -4,7 +4,8 @@
 //! with appropriate configuration.
 
 use crate::core::constants::CHROME_ARGS;
-use crate::core::engine::EngineType;
+use crate::core::engine::{EngineAdapter, EngineError, EngineType};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 /// Options for launching a browser.
@@ -22,6 +23,66 @@ pub struct LaunchOptions {
     pub verbose: bool,
     /// Additional Chrome arguments.
     pub args: Vec<String>,
+    /// Remote WebDriver server URL (e.g. `http://localhost:4444`).
+    ///
+    /// Set by [`LaunchOptions::webdriver`]. Nothing in this crate reads it:
+    /// [`launch_browser`] does not create a
+    /// [`WebDriverAdapter`](crate::browser::WebDriverAdapter) or any other
+    /// adapter from it.
+    pub remote_url: Option<String>,
+    /// Requested `browserName` capability for the WebDriver session (e.g.
+    /// `"firefox"`, `"safari"`, `"chrome"`).
+    ///
+    /// Only meaningful when `engine` is [`EngineType::Fantoccini`]. A direct
+    /// geckodriver/chromedriver endpoint ignores it since it only ever
+    /// serves one browser, but a Selenium Grid hub fronting multiple
+    /// browser nodes needs it to route the new session correctly.
+    pub browser_name: Option<String>,
+    /// Request automatic handling of native JavaScript dialogs (`alert`,
+    /// `confirm`, `prompt`, `beforeunload`).
+    ///
+    /// The flag is only stored: neither [`launch_browser`] nor any adapter
+    /// in this crate reads it, so an open dialog still blocks until a
+    /// caller calls [`crate::core::engine::EngineAdapter::accept_alert`] or
+    /// [`crate::core::engine::EngineAdapter::dismiss_alert`].
+    pub auto_dismiss_dialogs: bool,
+    /// Initial window size in CSS pixels, as `(width, height)`.
+    ///
+    /// For the chromiumoxide engine this becomes a `--window-size=W,H`
+    /// launch argument; for the fantoccini/WebDriver engine it is applied
+    /// after the session is created via the W3C Set Window Rect command
+    /// (see [`EngineAdapter::set_window_rect`]).
+    pub window_size: Option<(u32, u32)>,
+    /// Initial window position in screen pixels, as `(x, y)`.
+    ///
+    /// For the chromiumoxide engine this becomes a `--window-position=X,Y`
+    /// launch argument; for the fantoccini/WebDriver engine it is applied
+    /// via the same Set Window Rect command as [`LaunchOptions::window_size`].
+    pub window_position: Option<(i32, i32)>,
+    /// Start the window maximized, via the WebDriver Maximize Window
+    /// command (or the chromiumoxide `--start-maximized` argument).
+    ///
+    /// Takes precedence over [`LaunchOptions::window_size`] and
+    /// [`LaunchOptions::window_position`] when applied.
+    pub maximized: bool,
+    /// Override the page's device scale factor, emulating a HiDPI or
+    /// low-DPI display.
+    ///
+    /// Only meaningful for the chromiumoxide engine, which applies it via a
+    /// CDP `Emulation.setDeviceMetricsOverride` call alongside
+    /// [`LaunchOptions::window_size`]
+    /// (see [`EngineAdapter::set_device_metrics`]); the WebDriver protocol
+    /// has no equivalent command.
+    pub device_scale_factor: Option<f64>,
+    /// Engine-specific preferences to apply before launch (e.g. download
+    /// directory, disabling a feature), keyed by the engine's own
+    /// preference name.
+    ///
+    /// [`launch_browser`] writes them, for every engine, as JSON to
+    /// `preferences.json` in the user data directory. They are not
+    /// translated into Chromium arguments: [`LaunchOptions::all_chrome_args`]
+    /// does not include them.
+    pub preferences: HashMap<String, serde_json::Value>,
 }
 
 impl Default for LaunchOptions {
@@ -33,6 +94,14 @@ impl Default for LaunchOptions {
             slow_mo: 0,
             verbose: false,
             args: Vec::new(),
+            remote_url: None,
+            browser_name: None,
+            auto_dismiss_dialogs: false,
+            window_size: None,
+            window_position: None,
+            maximized: false,
+            device_scale_factor: None,
+            preferences: HashMap::new(),
         }
     }
 }
@@ -54,6 +123,16 @@ impl LaunchOptions {
         }
     }
 
+    /// Create options for the W3C WebDriver adapter, targeting a remote
+    /// geckodriver/chromedriver endpoint or Selenium Grid URL.
+    pub fn webdriver(remote_url: impl Into<String>) -> Self {
+        Self {
+            engine: EngineType::Fantoccini,
+            remote_url: Some(remote_url.into()),
+            ..Default::default()
+        }
+    }
+
     /// Set headless mode.
     pub fn headless(mut self, headless: bool) -> Self {
         self.headless = headless;
@@ -84,9 +163,69 @@ impl LaunchOptions {
         self
     }
 
-    /// Get all Chrome arguments (default + custom).
+    /// Request a specific `browserName` capability from the WebDriver
+    /// session, e.g. to route a Selenium Grid session to a Firefox node.
+    pub fn browser_name(mut self, browser_name: impl Into<String>) -> Self {
+        self.browser_name = Some(browser_name.into());
+        self
+    }
+
+    /// Set [`LaunchOptions::auto_dismiss_dialogs`].
+    pub fn auto_dismiss_dialogs(mut self, auto_dismiss_dialogs: bool) -> Self {
+        self.auto_dismiss_dialogs = auto_dismiss_dialogs;
+        self
+    }
+
+    /// Set the initial window size.
+    pub fn window_size(mut self, width: u32, height: u32) -> Self {
+        self.window_size = Some((width, height));
+        self
+    }
+
+    /// Set the initial window position.
+    pub fn window_position(mut self, x: i32, y: i32) -> Self {
+        self.window_position = Some((x, y));
+        self
+    }
+
+    /// Start the window maximized.
+    pub fn maximized(mut self, maximized: bool) -> Self {
+        self.maximized = maximized;
+        self
+    }
+
+    /// Override the page's device scale factor.
+    pub fn device_scale_factor(mut self, device_scale_factor: f64) -> Self {
+        self.device_scale_factor = Some(device_scale_factor);
+        self
+    }
+
+    /// Set a single engine preference, overwriting any existing value for
+    /// the same key.
+    pub fn preference(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.preferences.insert(key.into(), value.into());
+        self
+    }
+
+    /// Replace the full set of engine preferences.
+    pub fn with_preferences(mut self, preferences: HashMap<String, serde_json::Value>) -> Self {
+        self.preferences = preferences;
+        self
+    }
+
+    /// Get all Chrome arguments (default + window geometry + custom).
     pub fn all_chrome_args(&self) -> Vec<String> {
         let mut all_args: Vec<String> = CHROME_ARGS.iter().map(|s| s.to_string()).collect();
+        if self.maximized {
+            all_args.push("--start-maximized".to_string());
+        } else {
+            if let Some((width, height)) = self.window_size {
+                all_args.push(format!("--window-size={width},{height}"));
+            }
+            if let Some((x, y)) = self.window_position {
+                all_args.push(format!("--window-position={x},{y}"));
+            }
+        }
         all_args.extend(self.args.clone());
         all_args
     }
@@ -124,6 +263,72 @@ pub struct LaunchResult {
     pub browser: Browser,
 }
 
+/// Write `preferences` into the given profile directory's preference file.
+///
+/// For a WebDriver/Firefox-style profile this is the `user.js` file a real
+/// implementation would generate; since the underlying engine integration
+/// here is a placeholder, preferences are serialized as-is to
+/// `preferences.json` in `profile_dir` so they are still visible/inspectable
+/// on disk. Does nothing if `preferences` is empty.
+fn write_preferences(
+    profile_dir: &std::path::Path,
+    preferences: &HashMap<String, serde_json::Value>,
+) -> std::io::Result<()> {
+    if preferences.is_empty() {
+        return Ok(());
+    }
+    let contents = serde_json::to_vec_pretty(preferences)
+        .unwrap_or_else(|_| b"{}".to_vec());
+    std::fs::write(profile_dir.join("preferences.json"), contents)
+}
+
+/// Apply window geometry and device metrics from `options` to a live
+/// `adapter`, after a session has been established.
+///
+/// `launch_browser` only creates the user data directory and (for
+/// WebDriver/Firefox-style profiles) writes [`LaunchOptions::preferences`]
+/// to disk; it does not itself hold a live [`EngineAdapter`] session. Once
+/// a caller has launched the real engine and obtained an adapter, it should
+/// call this to apply [`LaunchOptions::window_size`],
+/// [`LaunchOptions::window_position`], [`LaunchOptions::maximized`], and
+/// [`LaunchOptions::device_scale_factor`].
+///
+/// For the chromiumoxide engine, window size/position are instead expected
+/// to already be baked into [`LaunchOptions::all_chrome_args`] at process
+/// launch time; this only issues the device metrics override, since CDP has
+/// no post-launch window-geometry call. For the fantoccini/WebDriver engine
+/// this issues the Maximize Window or Set Window Rect command.
+///
+/// # Errors
+///
+/// Returns an error if the adapter rejects or does not support the
+/// requested operation.
+pub async fn apply_window_options(
+    adapter: &dyn EngineAdapter,
+    options: &LaunchOptions,
+) -> Result<(), EngineError> {
+    if adapter.engine_type() == EngineType::Chromiumoxide {
+        // Geometry already went into the launch arguments.
+    } else if options.maximized {
+        adapter.maximize_window().await?;
+    } else if options.window_size.is_some() || options.window_position.is_some() {
+        let (width, height) = options
+            .window_size
+            .map_or((None, None), |(w, h)| (Some(w), Some(h)));
+        let (x, y) = options
+            .window_position
+            .map_or((None, None), |(x, y)| (Some(x), Some(y)));
+        adapter.set_window_rect(width, height, x, y).await?;
+    }
+
+    if let (Some(scale), Some((width, height))) = (options.device_scale_factor, options.window_size)
+    {
+        adapter.set_device_metrics(width, height, scale).await?;
+    }
+
+    Ok(())
+}
+
 /// Launch a browser with the given options.
 ///
 /// Note: This is a placeholder implementation. The actual implementation
@@ -153,6 +358,10 @@ pub async fn launch_browser(options: LaunchOptions) -> Result<LaunchResult, anyh
     // Create user data directory if it doesn't exist
     std::fs::create_dir_all(&user_data_dir)?;
 
+    // Persist engine preferences (download directory, feature toggles, ...)
+    // into the profile before any real engine process starts.
+    write_preferences(&user_data_dir, &options.preferences)?;
+
     // This is a placeholder - actual implementation would launch real browser
     let browser = Browser {
         engine: options.engine,
@@ -179,6 +388,96 @@ mod tests {
         assert_eq!(options.slow_mo, 0);
         assert!(!options.verbose);
         assert!(options.args.is_empty());
+        assert!(options.remote_url.is_none());
+        assert!(options.browser_name.is_none());
+        assert!(!options.auto_dismiss_dialogs);
+        assert!(options.window_size.is_none());
+        assert!(options.window_position.is_none());
+        assert!(!options.maximized);
+        assert!(options.device_scale_factor.is_none());
+        assert!(options.preferences.is_empty());
+    }
+
+    #[test]
+    fn launch_options_auto_dismiss_dialogs() {
+        let options = LaunchOptions::default().auto_dismiss_dialogs(true);
+        assert!(options.auto_dismiss_dialogs);
+    }
+
+    #[test]
+    fn launch_options_window_geometry() {
+        let options = LaunchOptions::default()
+            .window_size(1280, 720)
+            .window_position(10, 20)
+            .device_scale_factor(2.0);
+
+        assert_eq!(options.window_size, Some((1280, 720)));
+        assert_eq!(options.window_position, Some((10, 20)));
+        assert_eq!(options.device_scale_factor, Some(2.0));
+    }
+
+    #[test]
+    fn launch_options_maximized() {
+        let options = LaunchOptions::default().maximized(true);
+        assert!(options.maximized);
+    }
+
+    #[test]
+    fn launch_options_preferences() {
+        let options = LaunchOptions::default()
+            .preference("download.default_directory", "/tmp/downloads")
+            .preference("intl.accept_languages", "en-US");
+
+        assert_eq!(options.preferences.len(), 2);
+        assert_eq!(
+            options.preferences.get("download.default_directory"),
+            Some(&serde_json::json!("/tmp/downloads"))
+        );
+    }
+
+    #[test]
+    fn all_chrome_args_includes_window_size_and_position() {
+        let options = LaunchOptions::default()
+            .window_size(1280, 720)
+            .window_position(10, 20);
+        let args = options.all_chrome_args();
+
+        assert!(args.contains(&"--window-size=1280,720".to_string()));
+        assert!(args.contains(&"--window-position=10,20".to_string()));
+    }
+
+    #[test]
+    fn all_chrome_args_maximized_takes_precedence_over_window_size() {
+        let options = LaunchOptions::default()
+            .window_size(1280, 720)
+            .maximized(true);
+        let args = options.all_chrome_args();
+
+        assert!(args.contains(&"--start-maximized".to_string()));
+        assert!(!args.iter().any(|a| a.starts_with("--window-size")));
+    }
+
+    #[test]
+    fn write_preferences_skips_empty_map() {
+        let dir = std::env::temp_dir().join("browser-commander-test-empty-prefs");
+        std::fs::create_dir_all(&dir).unwrap();
+        write_preferences(&dir, &HashMap::new()).unwrap();
+        assert!(!dir.join("preferences.json").exists());
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn write_preferences_writes_json_file() {
+        let dir = std::env::temp_dir().join("browser-commander-test-prefs");
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut preferences = HashMap::new();
+        preferences.insert("foo".to_string(), serde_json::json!("bar"));
+
+        write_preferences(&dir, &preferences).unwrap();
+        let contents = std::fs::read_to_string(dir.join("preferences.json")).unwrap();
+        assert!(contents.contains("\"foo\""));
+        assert!(contents.contains("\"bar\""));
+        std::fs::remove_dir_all(&dir).ok();
     }
 
     #[test]
@@ -196,12 +495,25 @@ mod tests {
         assert_eq!(options.args, vec!["--custom-arg"]);
     }
 
+    #[test]
+    fn launch_options_browser_name() {
+        let options = LaunchOptions::webdriver("http://localhost:4444").browser_name("firefox");
+        assert_eq!(options.browser_name.as_deref(), Some("firefox"));
+    }
+
     #[test]
     fn launch_options_fantoccini() {
         let options = LaunchOptions::fantoccini();
         assert_eq!(options.engine, EngineType::Fantoccini);
     }
 
+    #[test]
+    fn launch_options_webdriver_sets_remote_url() {
+        let options = LaunchOptions::webdriver("http://localhost:4444");
+        assert_eq!(options.engine, EngineType::Fantoccini);
+        assert_eq!(options.remote_url.as_deref(), Some("http://localhost:4444"));
+    }
+
     #[test]
     fn all_chrome_args_includes_defaults() {
         let options = LaunchOptions::default();
@@ -6,9 +6,11 @@
 
 pub mod launcher;
 pub mod navigation_ops;
+pub mod webdriver;
 
-pub use launcher::{launch_browser, Browser, LaunchOptions, LaunchResult};
+pub use launcher::{apply_window_options, launch_browser, Browser, LaunchOptions, LaunchResult};
 pub use navigation_ops::{
     goto, verify_navigation, wait_for_navigation, wait_for_url_stabilization,
-    NavigationOptions, NavigationResult, NavigationVerificationResult, WaitUntil,
+    NavigationOptions, NavigationResult, NavigationVerificationResult, PageDiagnostics, WaitUntil,
 };
+pub use webdriver::{WebDriverAdapter, WebDriverCommand};
@@ -4,8 +4,10 @@
 //! verification and stabilization support.
 
 use crate::core::constants::TIMING;
-use crate::core::engine::{EngineAdapter, EngineError};
+use crate::core::engine::{EngineAdapter, EngineError, LoadState, NavEvent, PageActivityEvent};
 use crate::core::navigation::is_navigation_error;
+use futures::{FutureExt, StreamExt};
+use std::pin::Pin;
 use std::time::{Duration, Instant};
 
 /// Options for navigation operations.
@@ -27,6 +29,12 @@ pub struct NavigationOptions {
     pub stable_checks: u32,
     /// Interval between stability checks.
     pub check_interval: Duration,
+    /// Maximum number of in-flight requests still considered "idle", for
+    /// `wait_until: NetworkIdle`.
+    pub network_idle_threshold: u32,
+    /// How long in-flight requests must stay at or below
+    /// `network_idle_threshold` before the network is considered idle.
+    pub network_quiet_window: Duration,
 }
 
 impl Default for NavigationOptions {
@@ -40,6 +48,8 @@ impl Default for NavigationOptions {
             verification_timeout: TIMING.verification_timeout,
             stable_checks: 3,
             check_interval: Duration::from_secs(1),
+            network_idle_threshold: 0,
+            network_quiet_window: Duration::from_millis(500),
         }
     }
 }
@@ -66,6 +76,20 @@ impl std::fmt::Display for WaitUntil {
     }
 }
 
+/// Check whether an observed [`LoadState`] satisfies a requested
+/// [`WaitUntil`] condition.
+///
+/// `NetworkIdle` is the strongest guarantee and also satisfies a request for
+/// `Load`, which in turn satisfies a request for `DomContentLoaded`.
+fn load_state_satisfies(requested: WaitUntil, observed: LoadState) -> bool {
+    match (requested, observed) {
+        (WaitUntil::DomContentLoaded, _) => true,
+        (WaitUntil::Load, LoadState::Load | LoadState::NetworkIdle) => true,
+        (WaitUntil::NetworkIdle, LoadState::NetworkIdle) => true,
+        _ => false,
+    }
+}
+
 /// Result of a navigation verification.
 #[derive(Debug, Clone)]
 pub struct NavigationVerificationResult {
@@ -79,6 +103,42 @@ pub struct NavigationVerificationResult {
     pub attempts: u32,
 }
 
+/// Diagnostics collected from page activity during a navigation.
+///
+/// A navigation can be `verified: true` by URL while still having thrown JS
+/// errors or 404'd one of its assets; this buffer surfaces that instead of
+/// silently dropping it.
+#[derive(Debug, Clone, Default)]
+pub struct PageDiagnostics {
+    /// Console messages logged while the navigation ran.
+    pub console_messages: Vec<String>,
+    /// Uncaught exceptions thrown while the navigation ran.
+    pub exceptions: Vec<String>,
+    /// Responses with an HTTP status of 400 or greater.
+    pub failed_responses: Vec<(String, u16)>,
+}
+
+impl PageDiagnostics {
+    /// Whether any diagnostics were captured.
+    pub fn is_empty(&self) -> bool {
+        self.console_messages.is_empty() && self.exceptions.is_empty() && self.failed_responses.is_empty()
+    }
+
+    /// Record a single page activity event.
+    fn record(&mut self, event: PageActivityEvent) {
+        match event {
+            PageActivityEvent::ConsoleApiCalled { args, .. } => {
+                self.console_messages.push(args.join(" "))
+            }
+            PageActivityEvent::ExceptionThrown { text, .. } => self.exceptions.push(text),
+            PageActivityEvent::ResponseReceived { url, status } if status >= 400 => {
+                self.failed_responses.push((url, status));
+            }
+            _ => {}
+        }
+    }
+}
+
 /// Result of a navigation operation.
 #[derive(Debug, Clone)]
 pub struct NavigationResult {
@@ -90,6 +150,8 @@ pub struct NavigationResult {
     pub actual_url: Option<String>,
     /// The reason for the result.
     pub reason: Option<String>,
+    /// Diagnostics captured while the navigation ran.
+    pub diagnostics: PageDiagnostics,
 }
 
 impl NavigationResult {
@@ -100,6 +162,7 @@ impl NavigationResult {
             verified: true,
             actual_url: Some(actual_url),
             reason: Some("navigation completed".to_string()),
+            diagnostics: PageDiagnostics::default(),
         }
     }
 
@@ -110,6 +173,7 @@ impl NavigationResult {
             verified: false,
             actual_url: None,
             reason: Some(reason.into()),
+            diagnostics: PageDiagnostics::default(),
         }
     }
 }
@@ -134,10 +198,20 @@ pub async fn verify_navigation(
 ) -> Result<NavigationVerificationResult, EngineError> {
     let start_time = Instant::now();
     let mut attempts = 0u32;
+    let mut events = adapter.navigation_events();
+    let mut page_is_error = false;
 
     while start_time.elapsed() < options.verification_timeout {
         attempts += 1;
 
+        // Drain any navigation events already buffered, without blocking -
+        // an adapter that doesn't implement the stream never has any ready.
+        while let Some(Some(event)) = events.next().now_or_never() {
+            if let NavEvent::PageTypeChanged { error } = event {
+                page_is_error = error;
+            }
+        }
+
         let actual_url = match adapter.url().await {
             Ok(url) => url,
             Err(e) if is_navigation_error(&e.to_string()) => {
@@ -151,13 +225,19 @@ pub async fn verify_navigation(
             Err(e) => return Err(e),
         };
 
+        let error_suffix = if page_is_error {
+            " (page loaded an error document)"
+        } else {
+            ""
+        };
+
         // If expected URL is provided, verify it matches
         if let Some(expected) = expected_url {
             if actual_url == expected {
                 return Ok(NavigationVerificationResult {
                     verified: true,
                     actual_url,
-                    reason: "exact URL match".to_string(),
+                    reason: format!("exact URL match{error_suffix}"),
                     attempts,
                 });
             }
@@ -166,7 +246,7 @@ pub async fn verify_navigation(
                 return Ok(NavigationVerificationResult {
                     verified: true,
                     actual_url,
-                    reason: "URL pattern match".to_string(),
+                    reason: format!("URL pattern match{error_suffix}"),
                     attempts,
                 });
             }
@@ -176,7 +256,7 @@ pub async fn verify_navigation(
                 return Ok(NavigationVerificationResult {
                     verified: true,
                     actual_url,
-                    reason: "URL changed from start".to_string(),
+                    reason: format!("URL changed from start{error_suffix}"),
                     attempts,
                 });
             }
@@ -201,6 +281,13 @@ pub async fn verify_navigation(
 
 /// Wait for URL to stabilize (no more redirects).
 ///
+/// If the adapter implements [`EngineAdapter::navigation_events`], this
+/// drives off the event stream: the URL is considered stable once no
+/// `UrlChanged`/`NavigationCommitted` event arrives for `check_interval` and
+/// the requested [`WaitUntil`] state has been observed. Adapters that don't
+/// implement the stream (the default empty stream) fall back to polling
+/// `url()` directly.
+///
 /// # Arguments
 ///
 /// * `adapter` - The engine adapter to use
@@ -213,7 +300,30 @@ pub async fn verify_navigation(
 pub async fn wait_for_url_stabilization(
     adapter: &dyn EngineAdapter,
     options: &NavigationOptions,
-    _reason: &str,
+    reason: &str,
+) -> Result<bool, EngineError> {
+    let mut events = adapter.navigation_events();
+
+    // The wait for the first event is bounded by `timeout` too, so a live
+    // stream that stays silent reports a timeout instead of hanging.
+    match tokio::time::timeout(options.timeout, events.next()).await {
+        Ok(None) => wait_for_url_stabilization_polling(adapter, options).await,
+        Ok(Some(first_event)) => {
+            wait_for_url_stabilization_events(options, first_event, events).await
+        }
+        Err(_elapsed) => Ok(false),
+    }
+    .map(|stable| {
+        tracing::debug!("URL stabilization ({reason}) result: {stable}");
+        stable
+    })
+}
+
+/// Polling fallback: repeatedly sample `url()` until it stops changing for
+/// `stable_checks` consecutive samples.
+async fn wait_for_url_stabilization_polling(
+    adapter: &dyn EngineAdapter,
+    options: &NavigationOptions,
 ) -> Result<bool, EngineError> {
     let start_time = Instant::now();
     let mut stable_count = 0u32;
@@ -240,6 +350,58 @@ pub async fn wait_for_url_stabilization(
     Ok(true)
 }
 
+/// Event-driven implementation: consume the navigation-event stream until
+/// the URL has been quiet for `check_interval` and the requested load state
+/// has been observed, or `timeout` elapses.
+async fn wait_for_url_stabilization_events(
+    options: &NavigationOptions,
+    first_event: NavEvent,
+    mut events: Pin<Box<dyn futures::Stream<Item = NavEvent> + Send>>,
+) -> Result<bool, EngineError> {
+    let start_time = Instant::now();
+    let mut last_change = Instant::now();
+    let mut load_state_ok = false;
+
+    let mut apply = |event: &NavEvent, load_state_ok: &mut bool, last_change: &mut Instant| match event
+    {
+        NavEvent::UrlChanged(_) | NavEvent::NavigationCommitted => {
+            *last_change = Instant::now();
+        }
+        NavEvent::LoadStateChanged(state) => {
+            if load_state_satisfies(options.wait_until, *state) {
+                *load_state_ok = true;
+            }
+        }
+        NavEvent::PageTypeChanged { .. } => {}
+    };
+
+    apply(&first_event, &mut load_state_ok, &mut last_change);
+
+    loop {
+        if load_state_ok && last_change.elapsed() >= options.check_interval {
+            return Ok(true);
+        }
+        if start_time.elapsed() > options.timeout {
+            return Ok(false);
+        }
+
+        let remaining = options
+            .check_interval
+            .saturating_sub(last_change.elapsed())
+            .max(Duration::from_millis(1));
+
+        match tokio::time::timeout(remaining, events.next()).await {
+            Ok(Some(event)) => apply(&event, &mut load_state_ok, &mut last_change),
+            Ok(None) => return Ok(load_state_ok),
+            Err(_elapsed) => {
+                if load_state_ok {
+                    return Ok(true);
+                }
+            }
+        }
+    }
+}
+
 /// Navigate to a URL.
 ///
 /// # Arguments
@@ -257,6 +419,8 @@ pub async fn goto(
     options: &NavigationOptions,
 ) -> Result<NavigationResult, EngineError> {
     let start_url = adapter.url().await?;
+    let mut activity = adapter.page_activity();
+    let mut diagnostics = PageDiagnostics::default();
 
     // Wait for URL to stabilize before navigation (if requested)
     if options.wait_for_stable_url_before {
@@ -272,11 +436,20 @@ pub async fn goto(
         Err(e) => return Err(e),
     }
 
-    // Wait for URL to stabilize after navigation (if requested)
-    if options.wait_for_stable_url_after {
+    if options.wait_until == WaitUntil::NetworkIdle {
+        wait_for_network_idle(adapter, options).await?;
+    } else if options.wait_for_stable_url_after {
+        // Wait for URL to stabilize after navigation (if requested)
         wait_for_url_stabilization(adapter, options, "after navigation").await?;
     }
 
+    // Drain buffered page activity into the diagnostics buffer so a
+    // navigation that "succeeded" by URL still reports JS errors / 4xx+
+    // responses it produced along the way.
+    while let Some(Some(event)) = activity.next().now_or_never() {
+        diagnostics.record(event);
+    }
+
     // Verify navigation if requested
     if options.verify {
         let verification = verify_navigation(adapter, Some(url), &start_url, options).await?;
@@ -286,11 +459,75 @@ pub async fn goto(
             verified: verification.verified,
             actual_url: Some(verification.actual_url),
             reason: Some(verification.reason),
+            diagnostics,
         });
     }
 
     let actual_url = adapter.url().await?;
-    Ok(NavigationResult::success(actual_url))
+    Ok(NavigationResult {
+        diagnostics,
+        ..NavigationResult::success(actual_url)
+    })
+}
+
+/// Wait until the network has been idle (at most `network_idle_threshold`
+/// in-flight requests) for `network_quiet_window`.
+///
+/// Relies on [`EngineAdapter::page_activity`]; an adapter that doesn't
+/// implement it reports no requests at all, so the network is considered
+/// idle immediately.
+async fn wait_for_network_idle(
+    adapter: &dyn EngineAdapter,
+    options: &NavigationOptions,
+) -> Result<bool, EngineError> {
+    let mut activity = adapter.page_activity();
+    let start_time = Instant::now();
+    let mut in_flight: i64 = 0;
+    let mut quiet_since = Instant::now();
+
+    loop {
+        let is_idle = in_flight <= options.network_idle_threshold as i64;
+
+        if is_idle && quiet_since.elapsed() >= options.network_quiet_window {
+            return Ok(true);
+        }
+        if start_time.elapsed() > options.timeout {
+            return Ok(false);
+        }
+
+        let remaining = if is_idle {
+            options
+                .network_quiet_window
+                .saturating_sub(quiet_since.elapsed())
+                .max(Duration::from_millis(1))
+        } else {
+            options.network_quiet_window
+        };
+
+        match tokio::time::timeout(remaining, activity.next()).await {
+            Ok(Some(event)) => {
+                let was_busy = in_flight > options.network_idle_threshold as i64;
+                match event {
+                    PageActivityEvent::RequestStarted => in_flight += 1,
+                    PageActivityEvent::RequestFinished | PageActivityEvent::RequestFailed => {
+                        in_flight = (in_flight - 1).max(0);
+                    }
+                    _ => {}
+                }
+                // The quiet window restarts while busy and at the moment the
+                // count drops back to the threshold.
+                if was_busy || in_flight > options.network_idle_threshold as i64 {
+                    quiet_since = Instant::now();
+                }
+            }
+            Ok(None) => return Ok(true),
+            Err(_elapsed) => {
+                if in_flight <= options.network_idle_threshold as i64 {
+                    return Ok(true);
+                }
+            }
+        }
+    }
 }
 
 /// Wait for navigation to complete.
@@ -326,6 +563,36 @@ mod tests {
         assert!(options.wait_for_stable_url_after);
         assert!(options.verify);
         assert_eq!(options.stable_checks, 3);
+        assert_eq!(options.network_idle_threshold, 0);
+        assert_eq!(options.network_quiet_window, Duration::from_millis(500));
+    }
+
+    #[test]
+    fn page_diagnostics_records_failed_responses_only() {
+        let mut diagnostics = PageDiagnostics::default();
+        assert!(diagnostics.is_empty());
+
+        diagnostics.record(PageActivityEvent::ResponseReceived {
+            url: "https://example.com/ok".to_string(),
+            status: 200,
+        });
+        assert!(diagnostics.is_empty());
+
+        diagnostics.record(PageActivityEvent::ResponseReceived {
+            url: "https://example.com/missing".to_string(),
+            status: 404,
+        });
+        diagnostics.record(PageActivityEvent::ExceptionThrown {
+            text: "boom".to_string(),
+            stack: None,
+        });
+
+        assert!(!diagnostics.is_empty());
+        assert_eq!(
+            diagnostics.failed_responses,
+            vec![("https://example.com/missing".to_string(), 404)]
+        );
+        assert_eq!(diagnostics.exceptions, vec!["boom".to_string()]);
     }
 
     #[test]
@@ -335,6 +602,31 @@ mod tests {
         assert_eq!(WaitUntil::NetworkIdle.to_string(), "networkidle");
     }
 
+    #[test]
+    fn load_state_satisfies_dom_content_loaded_for_anything() {
+        assert!(load_state_satisfies(
+            WaitUntil::DomContentLoaded,
+            LoadState::DomContentLoaded
+        ));
+        assert!(load_state_satisfies(
+            WaitUntil::DomContentLoaded,
+            LoadState::NetworkIdle
+        ));
+    }
+
+    #[test]
+    fn load_state_satisfies_stronger_guarantees() {
+        assert!(load_state_satisfies(WaitUntil::Load, LoadState::NetworkIdle));
+        assert!(!load_state_satisfies(
+            WaitUntil::Load,
+            LoadState::DomContentLoaded
+        ));
+        assert!(!load_state_satisfies(
+            WaitUntil::NetworkIdle,
+            LoadState::Load
+        ));
+    }
+
     #[test]
     fn navigation_result_success() {
         let result = NavigationResult::success("https://example.com".to_string());
@@ -0,0 +1,1046 @@
+//! WebDriver (W3C) engine adapter.
+//!
+//! Implements [`EngineAdapter`] over the plain W3C WebDriver HTTP protocol,
+//! so browser-commander can drive geckodriver, chromedriver, or a remote
+//! Selenium Grid through the same trait used by the chromiumoxide backend.
+//! Unlike `fantoccini` (a higher-level WebDriver client), this talks the
+//! wire protocol directly so vendor-specific endpoints can be issued
+//! through [`WebDriverCommand`] without fighting an existing client's API.
+
+use crate::core::engine::{
+    ContextId, ElementInfo, EngineAdapter, EngineError, EngineType, NavigationEvent,
+    NavigationId, NavigationPhase, ScrollAlignment,
+};
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use futures::stream::{self, Stream};
+use reqwest::{Client, Method};
+use serde_json::{json, Value};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, Mutex};
+use url::Url;
+
+/// The W3C element identifier key used in WebDriver JSON responses.
+const ELEMENT_KEY: &str = "element-6066-11e4-a52e-4f735466cecf";
+
+/// A custom WebDriver command for vendor-specific endpoints (e.g. Selenium
+/// Grid session metadata, browser-specific extension commands) that fall
+/// outside the adapter's built-in method set.
+///
+/// Implementations describe how to build the request; [`WebDriverAdapter::execute`]
+/// issues it through the adapter's existing HTTP client and session.
+pub trait WebDriverCommand: Send + Sync {
+    /// Build the full endpoint URL for this command.
+    ///
+    /// `session_id` is empty when [`WebDriverCommand::is_new_session`]
+    /// returns `true`.
+    fn endpoint(&self, base: &Url, session_id: &str) -> Url;
+
+    /// The HTTP method and optional JSON request body.
+    fn method_and_body(&self) -> (Method, Option<Value>);
+
+    /// Whether this command creates a new session, and so has no existing
+    /// `session_id` to substitute into [`WebDriverCommand::endpoint`].
+    fn is_new_session(&self) -> bool {
+        false
+    }
+}
+
+/// Adapter driving a browser over the W3C WebDriver HTTP protocol.
+///
+/// `remote_url` points at the WebDriver server (e.g.
+/// `http://localhost:4444` for a local geckodriver/chromedriver, or a
+/// Selenium Grid hub URL). The session is created lazily on first use.
+pub struct WebDriverAdapter {
+    client: Client,
+    remote_url: Url,
+    session_id: Arc<Mutex<Option<String>>>,
+    browser_name: Option<String>,
+}
+
+impl WebDriverAdapter {
+    /// Create a new adapter for the given remote WebDriver endpoint.
+    ///
+    /// No HTTP request is made until the first call that needs a session.
+    /// The session is requested with empty capabilities, so against a
+    /// single-browser endpoint (geckodriver, chromedriver) the server's own
+    /// default applies; to target a specific browser on a multi-browser
+    /// Selenium Grid hub, use [`WebDriverAdapter::with_browser_name`].
+    pub fn new(remote_url: Url) -> Self {
+        Self {
+            client: Client::new(),
+            remote_url,
+            session_id: Arc::new(Mutex::new(None)),
+            browser_name: None,
+        }
+    }
+
+    /// Request a specific `browserName` capability (e.g. `"firefox"`,
+    /// `"safari"`, `"chrome"`) when the session is created.
+    ///
+    /// Without this, a Selenium Grid hub fronting multiple browser nodes has
+    /// no way to know which node to route the new session to; a direct
+    /// geckodriver/chromedriver endpoint ignores it since it only ever
+    /// serves one browser.
+    pub fn with_browser_name(mut self, browser_name: impl Into<String>) -> Self {
+        self.browser_name = Some(browser_name.into());
+        self
+    }
+
+    /// Get the current session id, creating a new session if needed.
+    async fn session(&self) -> Result<String, EngineError> {
+        Self::ensure_session(
+            &self.client,
+            &self.remote_url,
+            &self.session_id,
+            self.browser_name.as_deref(),
+        )
+        .await
+    }
+
+    /// Get the session id held by `session_id`, creating one via `client`
+    /// and `remote_url` if none exists yet. Takes its dependencies
+    /// individually rather than `&self` so it can also run inside the
+    /// `'static` polling task spawned by `navigation_lifecycle_events`.
+    async fn ensure_session(
+        client: &Client,
+        remote_url: &Url,
+        session_id: &Mutex<Option<String>>,
+        browser_name: Option<&str>,
+    ) -> Result<String, EngineError> {
+        let mut guard = session_id.lock().await;
+        if let Some(id) = guard.as_ref() {
+            return Ok(id.clone());
+        }
+
+        let id = Self::create_session_with(client, remote_url, browser_name).await?;
+        *guard = Some(id.clone());
+        Ok(id)
+    }
+
+    /// Start a brand new WebDriver session, independent of this adapter's
+    /// own lazily-created one. Used to back [`EngineAdapter::create_context`]
+    /// (a fresh session is the closest WebDriver analogue to an isolated CDP
+    /// browser context, since plain WebDriver has no concept of multiple
+    /// contexts per session).
+    async fn create_session(&self) -> Result<String, EngineError> {
+        Self::create_session_with(&self.client, &self.remote_url, self.browser_name.as_deref()).await
+    }
+
+    /// Start a brand new WebDriver session against `remote_url` using
+    /// `client`, without reading or writing any adapter's cached session id.
+    async fn create_session_with(
+        client: &Client,
+        remote_url: &Url,
+        browser_name: Option<&str>,
+    ) -> Result<String, EngineError> {
+        let always_match = match browser_name {
+            Some(name) => json!({ "browserName": name }),
+            None => json!({}),
+        };
+        let body = json!({
+            "capabilities": { "alwaysMatch": always_match }
+        });
+        let response = client
+            .post(Self::join_url(remote_url, "session"))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("session creation failed: {e}")))?;
+        let value = Self::parse_value(response).await?;
+        value
+            .get("sessionId")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .ok_or_else(|| EngineError::Browser("no sessionId in response".to_string()))
+    }
+
+    /// Wrap an already-created session id as an adapter, without the
+    /// lazy-session-creation behaviour of [`WebDriverAdapter::new`].
+    fn for_session(remote_url: Url, session_id: String, browser_name: Option<String>) -> Self {
+        Self {
+            client: Client::new(),
+            remote_url,
+            session_id: Arc::new(Mutex::new(Some(session_id))),
+            browser_name,
+        }
+    }
+
+    /// Build an endpoint URL relative to `remote_url`, without needing an
+    /// adapter instance (see [`WebDriverAdapter::url`]).
+    fn join_url(remote_url: &Url, path: &str) -> Url {
+        remote_url.join(path).unwrap_or_else(|_| remote_url.clone())
+    }
+
+    /// Build an endpoint URL relative to the remote WebDriver server.
+    fn url(&self, path: &str) -> Url {
+        Self::join_url(&self.remote_url, path)
+    }
+
+    /// Parse a WebDriver HTTP response, unwrapping the `{"value": ...}`
+    /// envelope and turning non-2xx statuses into [`EngineError::Browser`].
+    async fn parse_value(response: reqwest::Response) -> Result<Value, EngineError> {
+        let status = response.status();
+        let body: Value = response
+            .json()
+            .await
+            .map_err(|e| EngineError::Browser(format!("invalid WebDriver response: {e}")))?;
+
+        if !status.is_success() {
+            let message = body
+                .get("value")
+                .and_then(|v| v.get("message"))
+                .and_then(Value::as_str)
+                .unwrap_or("unknown WebDriver error");
+            return Err(EngineError::Browser(message.to_string()));
+        }
+
+        Ok(body.get("value").cloned().unwrap_or(Value::Null))
+    }
+
+    /// Find a single element and return its W3C element id.
+    async fn find_element(&self, selector: &str) -> Result<Option<String>, EngineError> {
+        let session_id = self.session().await?;
+        let response = self
+            .client
+            .post(self.url(&format!("session/{session_id}/element")))
+            .json(&json!({ "using": "css selector", "value": selector }))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("find element failed: {e}")))?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let value = Self::parse_value(response).await?;
+        Ok(value
+            .get(ELEMENT_KEY)
+            .and_then(Value::as_str)
+            .map(str::to_string))
+    }
+
+    /// Find every element matching `selector` and return their W3C
+    /// element ids, in document order.
+    async fn find_elements(&self, selector: &str) -> Result<Vec<String>, EngineError> {
+        let session_id = self.session().await?;
+        let response = self
+            .client
+            .post(self.url(&format!("session/{session_id}/elements")))
+            .json(&json!({ "using": "css selector", "value": selector }))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("find elements failed: {e}")))?;
+        let value = Self::parse_value(response).await?;
+        Ok(value
+            .as_array()
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|item| item.get(ELEMENT_KEY).and_then(Value::as_str).map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Fetch the subset of [`ElementInfo`] fields available over plain
+    /// WebDriver for an already-resolved element id, without re-running the
+    /// CSS lookup that produced it.
+    async fn element_info_for_id(&self, element_id: &str) -> Result<ElementInfo, EngineError> {
+        let session_id = self.session().await?;
+
+        let text_response = self
+            .client
+            .get(self.url(&format!("session/{session_id}/element/{element_id}/text")))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("text fetch failed: {e}")))?;
+        let text_content = Self::parse_value(text_response)
+            .await?
+            .as_str()
+            .map(str::to_string);
+
+        let displayed_response = self
+            .client
+            .get(self.url(&format!(
+                "session/{session_id}/element/{element_id}/displayed"
+            )))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("displayed check failed: {e}")))?;
+        let is_visible = Self::parse_value(displayed_response)
+            .await?
+            .as_bool()
+            .unwrap_or(false);
+
+        let enabled_response = self
+            .client
+            .get(self.url(&format!(
+                "session/{session_id}/element/{element_id}/enabled"
+            )))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("enabled check failed: {e}")))?;
+        let is_enabled = Self::parse_value(enabled_response)
+            .await?
+            .as_bool()
+            .unwrap_or(false);
+
+        Ok(ElementInfo {
+            tag_name: String::new(),
+            text_content,
+            is_visible,
+            is_enabled,
+            bounding_box: None,
+        })
+    }
+
+    /// Find a single element, returning [`EngineError::ElementNotFound`]
+    /// when there's no match.
+    async fn require_element(&self, selector: &str) -> Result<String, EngineError> {
+        self.find_element(selector)
+            .await?
+            .ok_or_else(|| EngineError::ElementNotFound(selector.to_string()))
+    }
+
+    /// Issue a vendor-specific [`WebDriverCommand`] against this adapter's
+    /// session, reusing its HTTP client and base URL.
+    pub async fn execute(&self, command: &dyn WebDriverCommand) -> Result<Value, EngineError> {
+        let session_id = if command.is_new_session() {
+            String::new()
+        } else {
+            self.session().await?
+        };
+        let endpoint = command.endpoint(&self.remote_url, &session_id);
+        let (method, body) = command.method_and_body();
+
+        let mut request = self.client.request(method, endpoint);
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("command failed: {e}")))?;
+        Self::parse_value(response).await
+    }
+}
+
+#[async_trait]
+impl EngineAdapter for WebDriverAdapter {
+    fn engine_type(&self) -> EngineType {
+        EngineType::Fantoccini
+    }
+
+    async fn url(&self) -> Result<String, EngineError> {
+        let session_id = self.session().await?;
+        let response = self
+            .client
+            .get(self.url(&format!("session/{session_id}/url")))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("get url failed: {e}")))?;
+        let value = Self::parse_value(response).await?;
+        value
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| EngineError::Browser("url response was not a string".to_string()))
+    }
+
+    async fn goto(&self, url: &str) -> Result<(), EngineError> {
+        let session_id = self.session().await?;
+        let response = self
+            .client
+            .post(self.url(&format!("session/{session_id}/url")))
+            .json(&json!({ "url": url }))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("goto failed: {e}")))?;
+        Self::parse_value(response).await.map(|_| ())
+    }
+
+    async fn query_selector(&self, selector: &str) -> Result<Option<ElementInfo>, EngineError> {
+        if self.find_element(selector).await?.is_none() {
+            return Ok(None);
+        }
+
+        Ok(Some(ElementInfo {
+            tag_name: String::new(),
+            text_content: self.text_content(selector).await?,
+            is_visible: self.is_visible(selector).await?,
+            is_enabled: self.is_enabled(selector).await?,
+            bounding_box: None,
+        }))
+    }
+
+    async fn query_selector_all(&self, selector: &str) -> Result<Vec<ElementInfo>, EngineError> {
+        let element_ids = self.find_elements(selector).await?;
+        let mut elements = Vec::with_capacity(element_ids.len());
+        for element_id in element_ids {
+            elements.push(self.element_info_for_id(&element_id).await?);
+        }
+        Ok(elements)
+    }
+
+    async fn count(&self, selector: &str) -> Result<usize, EngineError> {
+        Ok(self.find_elements(selector).await?.len())
+    }
+
+    async fn click(&self, selector: &str) -> Result<(), EngineError> {
+        let session_id = self.session().await?;
+        let element_id = self.require_element(selector).await?;
+        let response = self
+            .client
+            .post(self.url(&format!(
+                "session/{session_id}/element/{element_id}/click"
+            )))
+            .json(&json!({}))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("click failed: {e}")))?;
+        Self::parse_value(response).await.map(|_| ())
+    }
+
+    async fn fill(&self, selector: &str, text: &str) -> Result<(), EngineError> {
+        let session_id = self.session().await?;
+        let element_id = self.require_element(selector).await?;
+
+        let clear = self
+            .client
+            .post(self.url(&format!(
+                "session/{session_id}/element/{element_id}/clear"
+            )))
+            .json(&json!({}))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("clear failed: {e}")))?;
+        Self::parse_value(clear).await?;
+
+        self.type_text(selector, text).await
+    }
+
+    async fn type_text(&self, selector: &str, text: &str) -> Result<(), EngineError> {
+        let session_id = self.session().await?;
+        let element_id = self.require_element(selector).await?;
+        let response = self
+            .client
+            .post(self.url(&format!(
+                "session/{session_id}/element/{element_id}/value"
+            )))
+            .json(&json!({ "text": text }))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("type failed: {e}")))?;
+        Self::parse_value(response).await.map(|_| ())
+    }
+
+    async fn text_content(&self, selector: &str) -> Result<Option<String>, EngineError> {
+        let session_id = self.session().await?;
+        let Some(element_id) = self.find_element(selector).await? else {
+            return Ok(None);
+        };
+        let response = self
+            .client
+            .get(self.url(&format!(
+                "session/{session_id}/element/{element_id}/text"
+            )))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("text fetch failed: {e}")))?;
+        let value = Self::parse_value(response).await?;
+        Ok(value.as_str().map(str::to_string))
+    }
+
+    async fn input_value(&self, selector: &str) -> Result<Option<String>, EngineError> {
+        self.get_attribute(selector, "value").await
+    }
+
+    async fn get_attribute(
+        &self,
+        selector: &str,
+        attribute: &str,
+    ) -> Result<Option<String>, EngineError> {
+        let session_id = self.session().await?;
+        let Some(element_id) = self.find_element(selector).await? else {
+            return Ok(None);
+        };
+        let response = self
+            .client
+            .get(self.url(&format!(
+                "session/{session_id}/element/{element_id}/attribute/{attribute}"
+            )))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("attribute fetch failed: {e}")))?;
+        let value = Self::parse_value(response).await?;
+        Ok(value.as_str().map(str::to_string))
+    }
+
+    async fn is_visible(&self, selector: &str) -> Result<bool, EngineError> {
+        let session_id = self.session().await?;
+        let Some(element_id) = self.find_element(selector).await? else {
+            return Ok(false);
+        };
+        let response = self
+            .client
+            .get(self.url(&format!(
+                "session/{session_id}/element/{element_id}/displayed"
+            )))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("displayed check failed: {e}")))?;
+        let value = Self::parse_value(response).await?;
+        Ok(value.as_bool().unwrap_or(false))
+    }
+
+    async fn is_enabled(&self, selector: &str) -> Result<bool, EngineError> {
+        let session_id = self.session().await?;
+        let Some(element_id) = self.find_element(selector).await? else {
+            return Ok(false);
+        };
+        let response = self
+            .client
+            .get(self.url(&format!(
+                "session/{session_id}/element/{element_id}/enabled"
+            )))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("enabled check failed: {e}")))?;
+        let value = Self::parse_value(response).await?;
+        Ok(value.as_bool().unwrap_or(false))
+    }
+
+    async fn wait_for_selector(
+        &self,
+        selector: &str,
+        timeout_ms: u64,
+    ) -> Result<(), EngineError> {
+        let deadline = tokio::time::Instant::now() + std::time::Duration::from_millis(timeout_ms);
+        loop {
+            if self.find_element(selector).await?.is_some() {
+                return Ok(());
+            }
+            if tokio::time::Instant::now() >= deadline {
+                return Err(EngineError::Timeout(format!(
+                    "selector '{selector}' did not appear within {timeout_ms}ms"
+                )));
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+    }
+
+    async fn scroll_into_view(
+        &self,
+        selector: &str,
+        alignment: ScrollAlignment,
+    ) -> Result<(), EngineError> {
+        self.evaluate(&format!(
+            "document.querySelector({selector:?})?.scrollIntoView({{block: '{alignment}'}})"
+        ))
+        .await
+        .map(|_| ())
+    }
+
+    async fn evaluate(&self, script: &str) -> Result<Value, EngineError> {
+        let session_id = self.session().await?;
+        let response = self
+            .client
+            .post(self.url(&format!("session/{session_id}/execute/sync")))
+            .json(&json!({ "script": script, "args": [] }))
+            .send()
+            .await
+            .map_err(|e| EngineError::Evaluation(format!("execute/sync failed: {e}")))?;
+        Self::parse_value(response).await
+    }
+
+    async fn evaluate_async(&self, script: &str, timeout_ms: u64) -> Result<Value, EngineError> {
+        let session_id = self.session().await?;
+
+        // Bound how long the server itself waits for the completion
+        // callback before failing with its own script-timeout error.
+        self.client
+            .post(self.url(&format!("session/{session_id}/timeouts")))
+            .json(&json!({ "script": timeout_ms }))
+            .send()
+            .await
+            .map_err(|e| EngineError::Evaluation(format!("set script timeout failed: {e}")))?;
+
+        let response = self
+            .client
+            .post(self.url(&format!("session/{session_id}/execute/async")))
+            .json(&json!({ "script": script, "args": [] }))
+            .send()
+            .await
+            .map_err(|e| EngineError::Evaluation(format!("execute/async failed: {e}")))?;
+        Self::parse_value(response).await
+    }
+
+    async fn screenshot(&self) -> Result<Vec<u8>, EngineError> {
+        let session_id = self.session().await?;
+        let response = self
+            .client
+            .get(self.url(&format!("session/{session_id}/screenshot")))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("screenshot failed: {e}")))?;
+        let value = Self::parse_value(response).await?;
+        let base64 = value
+            .as_str()
+            .ok_or_else(|| EngineError::Browser("screenshot response was not a string".into()))?;
+        BASE64
+            .decode(base64)
+            .map_err(|e| EngineError::Browser(format!("invalid screenshot encoding: {e}")))
+    }
+
+    async fn bring_to_front(&self) -> Result<(), EngineError> {
+        self.evaluate("window.focus()").await.map(|_| ())
+    }
+
+    async fn page_source(&self) -> Result<String, EngineError> {
+        let session_id = self.session().await?;
+        let response = self
+            .client
+            .get(self.url(&format!("session/{session_id}/source")))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("page source fetch failed: {e}")))?;
+        let value = Self::parse_value(response).await?;
+        value
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| EngineError::Browser("source response was not a string".to_string()))
+    }
+
+    async fn wait_for_navigation(&self, timeout_ms: u64) -> Result<(), EngineError> {
+        let deadline = tokio::time::Instant::now() + std::time::Duration::from_millis(timeout_ms);
+        loop {
+            let ready_state = self.evaluate("return document.readyState").await?;
+            if ready_state.as_str() == Some("complete") {
+                return Ok(());
+            }
+            if tokio::time::Instant::now() >= deadline {
+                return Err(EngineError::Timeout(
+                    "navigation did not complete in time".to_string(),
+                ));
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+    }
+
+    async fn create_context(&self) -> Result<ContextId, EngineError> {
+        let session_id = self.create_session().await?;
+        Ok(ContextId(session_id))
+    }
+
+    async fn adapter_for_context(
+        &self,
+        context: &ContextId,
+    ) -> Result<Box<dyn EngineAdapter>, EngineError> {
+        Ok(Box::new(Self::for_session(
+            self.remote_url.clone(),
+            context.0.clone(),
+            self.browser_name.clone(),
+        )))
+    }
+
+    async fn dispose_context(&self, context: &ContextId) -> Result<(), EngineError> {
+        let response = self
+            .client
+            .delete(self.url(&format!("session/{}", context.0)))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("session deletion failed: {e}")))?;
+        Self::parse_value(response).await.map(|_| ())
+    }
+
+    async fn issue_webdriver(
+        &self,
+        http_method: &str,
+        endpoint: &str,
+        body: Option<Value>,
+    ) -> Result<Value, EngineError> {
+        let method = http_method
+            .parse::<Method>()
+            .map_err(|e| EngineError::Browser(format!("invalid HTTP method {http_method:?}: {e}")))?;
+        let session_id = self.session().await?;
+
+        let mut request = self
+            .client
+            .request(method, self.url(&format!("session/{session_id}/{endpoint}")));
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("raw WebDriver command failed: {e}")))?;
+        Self::parse_value(response).await
+    }
+
+    async fn get_alert_text(&self) -> Result<String, EngineError> {
+        let session_id = self.session().await?;
+        let response = self
+            .client
+            .get(self.url(&format!("session/{session_id}/alert/text")))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("get alert text failed: {e}")))?;
+        let value = Self::parse_value(response).await?;
+        value
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| EngineError::Browser("alert text response was not a string".to_string()))
+    }
+
+    async fn accept_alert(&self) -> Result<(), EngineError> {
+        let session_id = self.session().await?;
+        let response = self
+            .client
+            .post(self.url(&format!("session/{session_id}/alert/accept")))
+            .json(&json!({}))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("accept alert failed: {e}")))?;
+        Self::parse_value(response).await.map(|_| ())
+    }
+
+    async fn dismiss_alert(&self) -> Result<(), EngineError> {
+        let session_id = self.session().await?;
+        let response = self
+            .client
+            .post(self.url(&format!("session/{session_id}/alert/dismiss")))
+            .json(&json!({}))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("dismiss alert failed: {e}")))?;
+        Self::parse_value(response).await.map(|_| ())
+    }
+
+    async fn send_alert_text(&self, keys: &str) -> Result<(), EngineError> {
+        let session_id = self.session().await?;
+        let response = self
+            .client
+            .post(self.url(&format!("session/{session_id}/alert/text")))
+            .json(&json!({ "text": keys }))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("send alert text failed: {e}")))?;
+        Self::parse_value(response).await.map(|_| ())
+    }
+
+    async fn set_window_rect(
+        &self,
+        width: Option<u32>,
+        height: Option<u32>,
+        x: Option<i32>,
+        y: Option<i32>,
+    ) -> Result<(), EngineError> {
+        let session_id = self.session().await?;
+        let response = self
+            .client
+            .post(self.url(&format!("session/{session_id}/window/rect")))
+            .json(&json!({ "width": width, "height": height, "x": x, "y": y }))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("set window rect failed: {e}")))?;
+        Self::parse_value(response).await.map(|_| ())
+    }
+
+    async fn maximize_window(&self) -> Result<(), EngineError> {
+        let session_id = self.session().await?;
+        let response = self
+            .client
+            .post(self.url(&format!("session/{session_id}/window/maximize")))
+            .json(&json!({}))
+            .send()
+            .await
+            .map_err(|e| EngineError::Browser(format!("maximize window failed: {e}")))?;
+        Self::parse_value(response).await.map(|_| ())
+    }
+
+    fn navigation_lifecycle_events(&self) -> Pin<Box<dyn Stream<Item = NavigationEvent> + Send>> {
+        let client = self.client.clone();
+        let remote_url = self.remote_url.clone();
+        let session_id = self.session_id.clone();
+        let browser_name = self.browser_name.clone();
+        let (tx, rx) = mpsc::channel(16);
+
+        tokio::spawn(poll_navigation_lifecycle(
+            client,
+            remote_url,
+            session_id,
+            browser_name,
+            tx,
+        ));
+
+        Box::pin(stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|event| (event, rx))
+        }))
+    }
+}
+
+/// Poll `url()`/`document.readyState` over `client`/`remote_url`/`session_id`
+/// and translate the observed transitions into [`NavigationEvent`]s on
+/// `tx`, until the receiving end is dropped.
+///
+/// This backs [`WebDriverAdapter::navigation_lifecycle_events`] with
+/// polling-plus-readyState, since plain WebDriver has no push-based
+/// navigation events the way CDP's `Page.frameNavigated`/`Page.lifecycleEvent`
+/// do.
+async fn poll_navigation_lifecycle(
+    client: Client,
+    remote_url: Url,
+    session_id: Arc<Mutex<Option<String>>>,
+    browser_name: Option<String>,
+    tx: mpsc::Sender<NavigationEvent>,
+) {
+    const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+    let mut navigation_id = 0u64;
+    let mut last_url: Option<String> = None;
+    let mut announced_dom_content_loaded = false;
+    let mut announced_load_finished = false;
+
+    loop {
+        tokio::time::sleep(POLL_INTERVAL).await;
+
+        let Ok(session) = WebDriverAdapter::ensure_session(
+            &client,
+            &remote_url,
+            &session_id,
+            browser_name.as_deref(),
+        )
+        .await
+        else {
+            continue;
+        };
+
+        let polled = poll_url_and_ready_state(&client, &remote_url, &session).await;
+        let (url, ready_state) = match polled {
+            Ok(polled) => polled,
+            Err(e) => {
+                let url = last_url.clone().unwrap_or_default();
+                if tx
+                    .send(NavigationEvent {
+                        navigation_id: NavigationId(navigation_id),
+                        url,
+                        same_document: false,
+                        phase: NavigationPhase::Failed(e.to_string()),
+                    })
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+                continue;
+            }
+        };
+
+        if last_url.as_deref() != Some(url.as_str()) {
+            navigation_id += 1;
+            let same_document = last_url
+                .as_deref()
+                .is_some_and(|previous| is_same_document(previous, &url));
+            last_url = Some(url.clone());
+            announced_dom_content_loaded = false;
+            announced_load_finished = false;
+
+            let phase = if same_document {
+                NavigationPhase::Committed
+            } else {
+                NavigationPhase::Started
+            };
+            if tx
+                .send(NavigationEvent {
+                    navigation_id: NavigationId(navigation_id),
+                    url: url.clone(),
+                    same_document,
+                    phase,
+                })
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+
+        let phase = match ready_state.as_str() {
+            "interactive" if !announced_dom_content_loaded => {
+                announced_dom_content_loaded = true;
+                Some(NavigationPhase::DomContentLoaded)
+            }
+            "complete" if !announced_load_finished => {
+                announced_dom_content_loaded = true;
+                announced_load_finished = true;
+                Some(NavigationPhase::LoadFinished)
+            }
+            _ => None,
+        };
+
+        if let Some(phase) = phase {
+            if tx
+                .send(NavigationEvent {
+                    navigation_id: NavigationId(navigation_id),
+                    url: url.clone(),
+                    same_document: false,
+                    phase,
+                })
+                .await
+                .is_err()
+            {
+                return;
+            }
+        }
+    }
+}
+
+/// Fetch the current URL and `document.readyState` for `session` in a
+/// single round-trip pair.
+async fn poll_url_and_ready_state(
+    client: &Client,
+    remote_url: &Url,
+    session: &str,
+) -> Result<(String, String), EngineError> {
+    let url_response = client
+        .get(WebDriverAdapter::join_url(
+            remote_url,
+            &format!("session/{session}/url"),
+        ))
+        .send()
+        .await
+        .map_err(|e| EngineError::Browser(format!("get url failed: {e}")))?;
+    let url = WebDriverAdapter::parse_value(url_response)
+        .await?
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| EngineError::Browser("url response was not a string".to_string()))?;
+
+    let ready_state_response = client
+        .post(WebDriverAdapter::join_url(
+            remote_url,
+            &format!("session/{session}/execute/sync"),
+        ))
+        .json(&json!({ "script": "return document.readyState", "args": [] }))
+        .send()
+        .await
+        .map_err(|e| EngineError::Evaluation(format!("execute/sync failed: {e}")))?;
+    let ready_state = WebDriverAdapter::parse_value(ready_state_response)
+        .await?
+        .as_str()
+        .map(str::to_string)
+        .unwrap_or_default();
+
+    Ok((url, ready_state))
+}
+
+/// Whether `new_url` is a same-document (history/hash) navigation from
+/// `old_url`: everything but the fragment is unchanged.
+fn is_same_document(old_url: &str, new_url: &str) -> bool {
+    match (Url::parse(old_url), Url::parse(new_url)) {
+        (Ok(old), Ok(new)) => {
+            old != new
+                && old.scheme() == new.scheme()
+                && old.host_str() == new.host_str()
+                && old.port() == new.port()
+                && old.path() == new.path()
+                && old.query() == new.query()
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct ListSessions;
+
+    impl WebDriverCommand for ListSessions {
+        fn endpoint(&self, base: &Url, _session_id: &str) -> Url {
+            base.join("sessions").unwrap()
+        }
+
+        fn method_and_body(&self) -> (Method, Option<Value>) {
+            (Method::GET, None)
+        }
+
+        fn is_new_session(&self) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn webdriver_command_is_new_session() {
+        let command = ListSessions;
+        assert!(command.is_new_session());
+        let (method, body) = command.method_and_body();
+        assert_eq!(method, Method::GET);
+        assert!(body.is_none());
+    }
+
+    #[test]
+    fn webdriver_command_endpoint_uses_base() {
+        let base = Url::parse("http://localhost:4444/").unwrap();
+        let endpoint = ListSessions.endpoint(&base, "");
+        assert_eq!(endpoint.as_str(), "http://localhost:4444/sessions");
+    }
+
+    #[test]
+    fn adapter_engine_type_is_fantoccini() {
+        let adapter = WebDriverAdapter::new(Url::parse("http://localhost:4444/").unwrap());
+        assert_eq!(adapter.engine_type(), EngineType::Fantoccini);
+    }
+
+    #[test]
+    fn with_browser_name_sets_field() {
+        let adapter = WebDriverAdapter::new(Url::parse("http://localhost:4444/").unwrap())
+            .with_browser_name("firefox");
+        assert_eq!(adapter.browser_name.as_deref(), Some("firefox"));
+    }
+
+    #[tokio::test]
+    async fn adapter_for_context_wraps_session_without_creating_one() {
+        let adapter = WebDriverAdapter::new(Url::parse("http://localhost:4444/").unwrap());
+        let scoped = adapter
+            .adapter_for_context(&ContextId("existing-session".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(scoped.engine_type(), EngineType::Fantoccini);
+    }
+
+    #[tokio::test]
+    async fn issue_webdriver_rejects_invalid_http_method() {
+        let adapter = WebDriverAdapter::new(Url::parse("http://localhost:4444/").unwrap());
+        let result = adapter
+            .issue_webdriver("NOT A METHOD", "window/rect", None)
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn execute_cdp_unsupported_by_default() {
+        let adapter = WebDriverAdapter::new(Url::parse("http://localhost:4444/").unwrap());
+        let result = adapter.execute_cdp("Page.navigate", json!({})).await;
+        assert!(matches!(result, Err(EngineError::Browser(_))));
+    }
+
+    #[test]
+    fn is_same_document_detects_fragment_only_change() {
+        assert!(is_same_document(
+            "https://example.com/page",
+            "https://example.com/page#section"
+        ));
+        assert!(!is_same_document(
+            "https://example.com/page",
+            "https://example.com/other"
+        ));
+        assert!(!is_same_document(
+            "https://example.com/page",
+            "https://example.com/page"
+        ));
+    }
+}
@@ -3,8 +3,13 @@
 //! This module provides traits and types for abstracting over different
 //! browser automation engines (currently focused on Chromium-based browsers).
 
+use crate::core::constants::Timing;
 use async_trait::async_trait;
+use futures::stream::{self, Stream};
 use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
 use thiserror::Error;
 
 /// The type of browser automation engine being used.
@@ -95,12 +100,23 @@ pub struct ClickVerificationResult {
 /// Result of a scroll verification.
 #[derive(Debug, Clone)]
 pub struct ScrollVerificationResult {
-    /// Whether the scroll was verified as successful.
+    /// Whether the scroll was verified as successful: the element's
+    /// visible ratio met `threshold_percent` and its bounding box had
+    /// settled (see [`ScrollVerificationResult::stable`]).
     pub verified: bool,
     /// Whether the element is in the viewport.
     pub in_viewport: bool,
     /// Number of verification attempts.
     pub attempts: u32,
+    /// The fraction (0.0-1.0) of the element's bounding box that
+    /// intersects the viewport, as measured by
+    /// [`EngineAdapter::intersection_ratio`].
+    pub visible_ratio: f64,
+    /// Whether the element's bounding box was unchanged across two
+    /// consecutive animation frames, as measured by
+    /// [`EngineAdapter::is_bounding_box_stable`]. `false` while a
+    /// smooth-scroll animation is still in flight.
+    pub stable: bool,
 }
 
 /// Result of a fill verification.
@@ -114,6 +130,115 @@ pub struct FillVerificationResult {
     pub attempts: u32,
 }
 
+/// Opaque identifier for an isolated browser context created via
+/// [`EngineAdapter::create_context`], e.g. a CDP browser context id or a
+/// separate WebDriver session id, depending on the backing adapter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ContextId(pub String);
+
+/// Where an element should come to rest in the viewport after scrolling,
+/// mirroring the `block` option of the DOM `scrollIntoView({block: ...})`
+/// call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollAlignment {
+    /// Align the element's top edge with the top of the viewport.
+    Start,
+    /// Center the element within the viewport.
+    #[default]
+    Center,
+    /// Align the element's bottom edge with the bottom of the viewport.
+    End,
+    /// Scroll the minimum distance needed to bring the element fully into
+    /// view, without regard for where it lands.
+    Nearest,
+}
+
+impl std::fmt::Display for ScrollAlignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScrollAlignment::Start => write!(f, "start"),
+            ScrollAlignment::Center => write!(f, "center"),
+            ScrollAlignment::End => write!(f, "end"),
+            ScrollAlignment::Nearest => write!(f, "nearest"),
+        }
+    }
+}
+
+/// The vertical space occupied by sticky/fixed-positioned chrome (e.g. a
+/// fixed navbar or footer) that visually occludes part of the viewport,
+/// as measured by [`EngineAdapter::sticky_viewport_offsets`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ViewportOffsets {
+    /// Height, in pixels, of sticky/fixed chrome anchored to the top edge.
+    pub top: f64,
+    /// Height, in pixels, of sticky/fixed chrome anchored to the bottom edge.
+    pub bottom: f64,
+}
+
+/// Result of a point hit-test, i.e. whether a click at an element's
+/// location would actually land on it, as measured by
+/// [`EngineAdapter::hit_test_occlusion`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OcclusionInfo {
+    /// Whether the element is occluded (unreachable by a real click).
+    pub occluded: bool,
+    /// Tag name of the element actually hit at the sample point(s), when
+    /// occlusion is caused by another element covering this one.
+    pub occluding_tag: Option<String>,
+    /// `className` of the occluding element.
+    pub occluding_class: Option<String>,
+    /// Human-readable explanation, e.g. `"covered by another element"`,
+    /// `"pointer-events: none"`, or `"zero-size bounding box"`.
+    pub reason: Option<String>,
+}
+
+/// Scroll-snap correction applied for an element, as measured by
+/// [`EngineAdapter::apply_scroll_snap`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ScrollSnapInfo {
+    /// Whether an ancestor scroll-snap container (computed
+    /// `scroll-snap-type` other than `none`) was found.
+    pub detected: bool,
+    /// The corrected `scrollTop` the snap container was set to, landing
+    /// the element at its nearest snap position, when a container was
+    /// detected.
+    pub snap_offset: Option<f64>,
+}
+
+/// Result of a visible-text lookup, as measured by
+/// [`EngineAdapter::find_by_text`].
+#[derive(Debug, Clone, Default)]
+pub struct TextMatchInfo {
+    /// Whether any element containing the text was found.
+    pub found: bool,
+    /// Total number of matching text nodes found in the document.
+    pub match_count: usize,
+    /// A generated selector that uniquely targets the chosen (first)
+    /// match's enclosing element, present whenever `found` is `true`.
+    pub selector: Option<String>,
+    /// The chosen match's enclosing element bounding box
+    /// (x, y, width, height), present whenever `found` is `true`.
+    pub bounding_box: Option<(f64, f64, f64, f64)>,
+}
+
+/// Handle to a document-start script registered via
+/// [`EngineAdapter::add_script_on_new_document`], opaque except for passing
+/// back to [`EngineAdapter::remove_script_on_new_document`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptHandle(pub String);
+
+/// An event delivered from the page via a binding registered with
+/// [`EngineAdapter::expose_binding`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BindingEvent {
+    /// The name the binding was exposed under.
+    pub binding: String,
+    /// The raw payload the page passed to the binding function, left
+    /// unparsed (typically JSON) so callers can deserialize into whatever
+    /// shape they expect.
+    pub payload: String,
+}
+
 /// Pre-click state captured for verification.
 #[derive(Debug, Clone, Default)]
 pub struct PreClickState {
@@ -131,6 +256,21 @@ pub struct PreClickState {
     pub class_name: Option<String>,
     /// Whether the element is connected to the DOM.
     pub is_connected: bool,
+    /// The element's computed ARIA role (explicit `role` attribute or the
+    /// element's implicit role, e.g. `button` for a `<button>`).
+    pub role: Option<String>,
+    /// The aria-checked attribute value (tri-state: "true"/"false"/"mixed").
+    pub aria_checked: Option<String>,
+    /// The aria-disabled attribute value.
+    pub aria_disabled: Option<String>,
+    /// The aria-current attribute value.
+    pub aria_current: Option<String>,
+    /// The aria-invalid attribute value.
+    pub aria_invalid: Option<String>,
+    /// The element's value (for form elements).
+    pub value: Option<String>,
+    /// The element's text content.
+    pub text_content: Option<String>,
 }
 
 /// Trait for browser engine adapters.
@@ -192,20 +332,1340 @@ pub trait EngineAdapter: Send + Sync {
         timeout_ms: u64,
     ) -> Result<(), EngineError>;
 
-    /// Scroll an element into view.
-    async fn scroll_into_view(&self, selector: &str) -> Result<(), EngineError>;
+    /// Scroll an element into view, coming to rest per the requested
+    /// [`ScrollAlignment`].
+    async fn scroll_into_view(
+        &self,
+        selector: &str,
+        alignment: ScrollAlignment,
+    ) -> Result<(), EngineError>;
 
     /// Evaluate JavaScript in the page context.
     async fn evaluate(&self, script: &str) -> Result<serde_json::Value, EngineError>;
 
+    /// Execute an asynchronous script, resolving when the page invokes the
+    /// implicit completion callback (the script's final `arguments[...]`
+    /// parameter, per the WebDriver "Execute Async Script" convention) or
+    /// the `Promise` it returns settles, and erroring if `timeout_ms`
+    /// elapses first.
+    ///
+    /// The default implementation reports this adapter as not supporting
+    /// async script evaluation.
+    async fn evaluate_async(
+        &self,
+        script: &str,
+        timeout_ms: u64,
+    ) -> Result<serde_json::Value, EngineError> {
+        let _ = (script, timeout_ms);
+        Err(EngineError::Evaluation(format!(
+            "{} adapter does not support async script evaluation",
+            self.engine_type()
+        )))
+    }
+
     /// Take a screenshot.
     async fn screenshot(&self) -> Result<Vec<u8>, EngineError>;
 
+    /// Get the serialized HTML of the whole document (the `outerHTML` of
+    /// the document element).
+    ///
+    /// The default implementation evaluates `document.documentElement
+    /// .outerHTML`, which works on any adapter that supports
+    /// [`EngineAdapter::evaluate`]. Adapters with a faster native mechanism
+    /// (e.g. WebDriver's page-source endpoint) should override this.
+    async fn page_source(&self) -> Result<String, EngineError> {
+        let value = self
+            .evaluate("return document.documentElement.outerHTML")
+            .await?;
+        value
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| EngineError::Evaluation("page_source result was not a string".to_string()))
+    }
+
+    /// Get the serialized HTML (`outerHTML`) of a single element.
+    ///
+    /// Returns `None` if no element matches `selector`. The default
+    /// implementation evaluates a `querySelector(...).outerHTML` script.
+    async fn outer_html(&self, selector: &str) -> Result<Option<String>, EngineError> {
+        let script = format!("return document.querySelector({selector:?})?.outerHTML ?? null");
+        let value = self.evaluate(&script).await?;
+        Ok(value.as_str().map(str::to_string))
+    }
+
+    /// Measure the usable viewport band beneath/above sticky or fixed
+    /// chrome (e.g. a fixed navbar or footer) that visually occludes
+    /// elements [`is_in_viewport`](crate::elements::is_in_viewport) and
+    /// [`needs_scrolling`](crate::elements::needs_scrolling) would otherwise
+    /// report as unobstructed.
+    ///
+    /// The default implementation evaluates JS that scans the document for
+    /// elements computed as `position: fixed` or `position: sticky` and
+    /// anchored flush to the top or bottom edge of the viewport, summing
+    /// their heights into a [`ViewportOffsets`]. Adapters with a faster
+    /// native mechanism should override this.
+    async fn sticky_viewport_offsets(&self) -> Result<ViewportOffsets, EngineError> {
+        let script = r#"return (function() {
+            const vh = window.innerHeight;
+            let top = 0;
+            let bottom = 0;
+            document.querySelectorAll('*').forEach((el) => {
+                const style = window.getComputedStyle(el);
+                if (style.position !== 'fixed' && style.position !== 'sticky') return;
+                const rect = el.getBoundingClientRect();
+                if (rect.width === 0 || rect.height === 0) return;
+                if (Math.abs(rect.top) < 1) {
+                    top = Math.max(top, rect.bottom);
+                } else if (Math.abs(rect.bottom - vh) < 1) {
+                    bottom = Math.max(bottom, vh - rect.top);
+                }
+            });
+            return { top: top, bottom: bottom };
+        })()"#;
+        let value = self.evaluate(script).await?;
+        Ok(ViewportOffsets {
+            top: value.get("top").and_then(|v| v.as_f64()).unwrap_or(0.0),
+            bottom: value.get("bottom").and_then(|v| v.as_f64()).unwrap_or(0.0),
+        })
+    }
+
+    /// Point hit-test whether an element is actually reachable by a click,
+    /// as opposed to merely rendered (which is all [`is_visible`](
+    /// EngineAdapter::is_visible) checks). A visible element can still be
+    /// covered by a modal overlay, cookie banner, or toast, in which case a
+    /// real click would land on that covering element instead.
+    ///
+    /// The default implementation evaluates JS that reads the element's
+    /// bounding box, clamps its center point into the viewport, and calls
+    /// `document.elementFromPoint` there and at a few corner points (in
+    /// case the element's true shape doesn't cover its own center, e.g. an
+    /// `L`-shaped wrapper). The element passes if any sampled point hits
+    /// the element itself or one of its descendants/ancestors. Adapters
+    /// with a faster native mechanism should override this.
+    async fn hit_test_occlusion(&self, selector: &str) -> Result<OcclusionInfo, EngineError> {
+        let script = format!(
+            r#"return (function() {{
+                const el = document.querySelector({selector:?});
+                if (!el) return {{ occluded: true, reason: 'element not found' }};
+                const rect = el.getBoundingClientRect();
+                if (rect.width === 0 || rect.height === 0) {{
+                    return {{ occluded: true, reason: 'zero-size bounding box' }};
+                }}
+                if (window.getComputedStyle(el).pointerEvents === 'none') {{
+                    return {{ occluded: true, reason: 'pointer-events: none' }};
+                }}
+                const vw = window.innerWidth;
+                const vh = window.innerHeight;
+                const clamp = (v, lo, hi) => Math.min(Math.max(v, lo), hi);
+                const points = [
+                    [rect.left + rect.width / 2, rect.top + rect.height / 2],
+                    [rect.left + 1, rect.top + 1],
+                    [rect.right - 1, rect.top + 1],
+                    [rect.left + 1, rect.bottom - 1],
+                    [rect.right - 1, rect.bottom - 1],
+                ].map(([x, y]) => [clamp(x, 0, vw - 1), clamp(y, 0, vh - 1)]);
+                let coveringEl = null;
+                for (const [x, y] of points) {{
+                    const hit = document.elementFromPoint(x, y);
+                    if (hit && (hit === el || el.contains(hit) || hit.contains(el))) {{
+                        return {{ occluded: false }};
+                    }}
+                    if (!coveringEl) coveringEl = hit;
+                }}
+                return {{
+                    occluded: true,
+                    reason: 'covered by another element',
+                    tag: coveringEl ? coveringEl.tagName.toLowerCase() : null,
+                    className: (coveringEl && coveringEl.className) ? String(coveringEl.className) : null,
+                }};
+            }})()"#
+        );
+        let value = self.evaluate(&script).await?;
+        Ok(OcclusionInfo {
+            occluded: value
+                .get("occluded")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(true),
+            occluding_tag: value.get("tag").and_then(|v| v.as_str()).map(str::to_string),
+            occluding_class: value
+                .get("className")
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            reason: value.get("reason").and_then(|v| v.as_str()).map(str::to_string),
+        })
+    }
+
+    /// Correct the scroll position of an element's nearest CSS scroll-snap
+    /// ancestor so the element lands fully revealed at its nearest snap
+    /// position, rather than wherever a raw centering offset happened to
+    /// leave it (which the container would otherwise immediately snap away
+    /// from on the next scroll event).
+    ///
+    /// The default implementation evaluates JS that walks up from the
+    /// element looking for an ancestor whose computed `scroll-snap-type`
+    /// is not `none`. If found, it reads the element's `scroll-snap-align`
+    /// to determine the intended resting edge (`start`/`end`/`center`), or
+    /// falls back to the nearest position that fully reveals the element
+    /// when the element declares no snap alignment of its own, and sets
+    /// the container's `scrollTop` directly. Adapters with a faster native
+    /// mechanism should override this.
+    async fn apply_scroll_snap(&self, selector: &str) -> Result<ScrollSnapInfo, EngineError> {
+        let script = format!(
+            r#"return (function() {{
+                const el = document.querySelector({selector:?});
+                if (!el) return {{ detected: false }};
+
+                let container = null;
+                for (let node = el.parentElement; node; node = node.parentElement) {{
+                    const type = window.getComputedStyle(node).scrollSnapType;
+                    if (type && type !== 'none') {{
+                        container = node;
+                        break;
+                    }}
+                }}
+                if (!container) return {{ detected: false }};
+
+                const containerRect = container.getBoundingClientRect();
+                const elRect = el.getBoundingClientRect();
+                const elTop = container.scrollTop + (elRect.top - containerRect.top);
+                const elBottom = elTop + elRect.height;
+
+                const align = window.getComputedStyle(el).scrollSnapAlign || 'none';
+                let target;
+                if (align.includes('start')) {{
+                    target = elTop;
+                }} else if (align.includes('end')) {{
+                    target = elBottom - container.clientHeight;
+                }} else if (align.includes('center')) {{
+                    target = elTop - (container.clientHeight - elRect.height) / 2;
+                }} else {{
+                    // No snap-align of its own: scroll the minimum distance
+                    // that fully reveals it within the container.
+                    target = Math.min(elTop, Math.max(container.scrollTop, elBottom - container.clientHeight));
+                }}
+
+                const maxScroll = container.scrollHeight - container.clientHeight;
+                target = Math.max(0, Math.min(target, maxScroll));
+
+                container.scrollTop = target;
+                return {{ detected: true, snapOffset: target }};
+            }})()"#
+        );
+        let value = self.evaluate(&script).await?;
+        Ok(ScrollSnapInfo {
+            detected: value
+                .get("detected")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            snap_offset: value.get("snapOffset").and_then(|v| v.as_f64()),
+        })
+    }
+
+    /// Measure the fraction (0.0-1.0) of an element's bounding box that
+    /// currently intersects the viewport, for `threshold_percent`-aware
+    /// scroll verification (see [`crate::interactions::verify_scroll`]),
+    /// which a plain [`EngineAdapter::is_visible`] check can't express
+    /// since it only reports whether any part of the element is visible.
+    ///
+    /// The default implementation evaluates JS that clips the element's
+    /// `getBoundingClientRect()` against `innerWidth`/`innerHeight` and
+    /// divides the clipped area by the element's own area. Returns `0.0`
+    /// if the element doesn't exist or has a zero-size bounding box.
+    /// Adapters with a faster native mechanism (e.g. a real
+    /// `IntersectionObserver` callback) should override this.
+    async fn intersection_ratio(&self, selector: &str) -> Result<f64, EngineError> {
+        let script = format!(
+            r#"return (function() {{
+                const el = document.querySelector({selector:?});
+                if (!el) return 0;
+                const rect = el.getBoundingClientRect();
+                if (rect.width === 0 || rect.height === 0) return 0;
+                const vw = window.innerWidth;
+                const vh = window.innerHeight;
+                const left = Math.max(rect.left, 0);
+                const top = Math.max(rect.top, 0);
+                const right = Math.min(rect.right, vw);
+                const bottom = Math.min(rect.bottom, vh);
+                const visibleWidth = Math.max(0, right - left);
+                const visibleHeight = Math.max(0, bottom - top);
+                return (visibleWidth * visibleHeight) / (rect.width * rect.height);
+            }})()"#
+        );
+        let value = self.evaluate(&script).await?;
+        Ok(value.as_f64().unwrap_or(0.0))
+    }
+
+    /// Sample an element's bounding box across two consecutive
+    /// `requestAnimationFrame` callbacks and report whether it was
+    /// unchanged, borrowing Playwright's actionability-stability idea so a
+    /// caller doesn't accept an in-flight smooth-scroll animation as
+    /// already settled.
+    ///
+    /// The default implementation evaluates an async script (see
+    /// [`EngineAdapter::evaluate_async`]) that resolves once the second
+    /// frame has been sampled. Adapters that don't support async script
+    /// evaluation report the element as stable unconditionally, since
+    /// there's no way to sample frames.
+    async fn is_bounding_box_stable(
+        &self,
+        selector: &str,
+        timeout_ms: u64,
+    ) -> Result<bool, EngineError> {
+        let script = format!(
+            r#"return new Promise((resolve) => {{
+                const el = document.querySelector({selector:?});
+                if (!el) {{ resolve(true); return; }}
+                const sample = () => {{
+                    const r = el.getBoundingClientRect();
+                    return [r.left, r.top, r.width, r.height];
+                }};
+                const first = sample();
+                requestAnimationFrame(() => {{
+                    requestAnimationFrame(() => {{
+                        const second = sample();
+                        resolve(first.every((v, i) => v === second[i]));
+                    }});
+                }});
+            }})"#
+        );
+        match self.evaluate_async(&script, timeout_ms).await {
+            Ok(value) => Ok(value.as_bool().unwrap_or(true)),
+            Err(_) => Ok(true),
+        }
+    }
+
+    /// Locate the first element containing `text`, without requiring a CSS
+    /// selector — a text-fragment style locator for pages where the
+    /// human-visible label is known but no stable selector is.
+    ///
+    /// `prefix` and `suffix`, when given, disambiguate between repeated
+    /// occurrences of `text` by requiring the surrounding context to match
+    /// too, mirroring the `#:~:text=prefix-,start,end,-suffix` URL Text
+    /// Fragment directive. `highlight` wraps the chosen match in a `<mark>`
+    /// element so it's visible to the user once scrolled into view.
+    ///
+    /// The default implementation evaluates JS that walks the document
+    /// with a `TreeWalker` over visible text nodes, flattens them into a
+    /// single searchable string (so prefix/suffix context can span node
+    /// boundaries), and finds the first occurrence of `text` whose
+    /// surrounding context (and, if `whole_word` is set, word boundary)
+    /// matches. The enclosing element of the chosen match is tagged with a
+    /// marker attribute so it can be targeted by a plain CSS selector
+    /// afterwards, and its bounding box and the total match count are
+    /// reported alongside it. Adapters with a faster native mechanism
+    /// (e.g. the browser's own Text Fragment resolution) should override
+    /// this.
+    async fn find_by_text(
+        &self,
+        text: &str,
+        case_insensitive: bool,
+        whole_word: bool,
+        prefix: Option<&str>,
+        suffix: Option<&str>,
+        highlight: bool,
+    ) -> Result<TextMatchInfo, EngineError> {
+        let prefix_js = match prefix {
+            Some(p) => format!("{p:?}"),
+            None => "null".to_string(),
+        };
+        let suffix_js = match suffix {
+            Some(s) => format!("{s:?}"),
+            None => "null".to_string(),
+        };
+        let script = format!(
+            r#"return (function() {{
+                const needle = {text:?};
+                const prefix = {prefix_js};
+                const suffix = {suffix_js};
+                const caseInsensitive = {case_insensitive};
+                const wholeWord = {whole_word};
+                const highlight = {highlight};
+                const fold = (s) => caseInsensitive ? s.toLowerCase() : s;
+                const target = fold(needle);
+                const pre = prefix ? fold(prefix) : null;
+                const post = suffix ? fold(suffix) : null;
+                const escapeRe = (s) => s.replace(/[.*+?^${{}}()|[\]\\]/g, '\\$&');
+                const wordRe = wholeWord
+                    ? new RegExp('(^|\\W)' + escapeRe(target) + '($|\\W)')
+                    : null;
+
+                const walker = document.createTreeWalker(
+                    document.body,
+                    NodeFilter.SHOW_TEXT,
+                    {{
+                        acceptNode(node) {{
+                            if (!node.nodeValue || !node.nodeValue.trim()) {{
+                                return NodeFilter.FILTER_REJECT;
+                            }}
+                            const parent = node.parentElement;
+                            if (!parent) return NodeFilter.FILTER_REJECT;
+                            const style = window.getComputedStyle(parent);
+                            if (style.display === 'none' || style.visibility === 'hidden') {{
+                                return NodeFilter.FILTER_REJECT;
+                            }}
+                            return NodeFilter.FILTER_ACCEPT;
+                        }},
+                    }}
+                );
+
+                let flat = '';
+                const segments = [];
+                let node;
+                while ((node = walker.nextNode())) {{
+                    const start = flat.length;
+                    flat += node.nodeValue + ' ';
+                    segments.push({{ node, start, end: flat.length }});
+                }}
+
+                const haystack = fold(flat);
+                const found = [];
+                let searchFrom = 0;
+                while (true) {{
+                    const idx = haystack.indexOf(target, searchFrom);
+                    if (idx === -1) break;
+                    searchFrom = idx + Math.max(target.length, 1);
+
+                    const before = haystack.slice(Math.max(0, idx - (pre ? pre.length : 0)), idx);
+                    const after = haystack.slice(
+                        idx + target.length,
+                        idx + target.length + (post ? post.length : 0)
+                    );
+                    const prefixOk = !pre || before.endsWith(pre);
+                    const suffixOk = !post || after.startsWith(post);
+                    const wordOk = !wordRe
+                        || wordRe.test(haystack.slice(Math.max(0, idx - 1), idx + target.length + 1));
+
+                    if (prefixOk && suffixOk && wordOk) found.push(idx);
+                }}
+
+                if (found.length === 0) return {{ found: false, matchCount: 0 }};
+
+                const chosenIdx = found[0];
+                const segment = segments.find((s) => chosenIdx >= s.start && chosenIdx < s.end);
+                if (!segment) return {{ found: false, matchCount: 0 }};
+
+                document
+                    .querySelectorAll('[data-bc-text-match]')
+                    .forEach((el) => el.removeAttribute('data-bc-text-match'));
+
+                const el = segment.node.parentElement;
+                el.setAttribute('data-bc-text-match', '1');
+
+                if (highlight) {{
+                    const localStart = chosenIdx - segment.start;
+                    const localEnd = Math.min(localStart + target.length, segment.node.nodeValue.length);
+                    if (localEnd > localStart) {{
+                        const range = document.createRange();
+                        range.setStart(segment.node, Math.max(0, localStart));
+                        range.setEnd(segment.node, localEnd);
+                        const mark = document.createElement('mark');
+                        mark.setAttribute('data-bc-text-highlight', '1');
+                        range.surroundContents(mark);
+                    }}
+                }}
+
+                const rect = el.getBoundingClientRect();
+
+                return {{
+                    found: true,
+                    matchCount: found.length,
+                    selector: '[data-bc-text-match="1"]',
+                    boundingBox: [rect.x, rect.y, rect.width, rect.height],
+                }};
+            }})()"#
+        );
+        let value = self.evaluate(&script).await?;
+        let bounding_box = value.get("boundingBox").and_then(|v| v.as_array()).and_then(|arr| {
+            if arr.len() == 4 {
+                Some((
+                    arr[0].as_f64()?,
+                    arr[1].as_f64()?,
+                    arr[2].as_f64()?,
+                    arr[3].as_f64()?,
+                ))
+            } else {
+                None
+            }
+        });
+        Ok(TextMatchInfo {
+            found: value.get("found").and_then(|v| v.as_bool()).unwrap_or(false),
+            match_count: value
+                .get("matchCount")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0) as usize,
+            selector: value.get("selector").and_then(|v| v.as_str()).map(str::to_string),
+            bounding_box,
+        })
+    }
+
+    /// Register `script` to run at the start of every subsequent document
+    /// load, before any of the page's own scripts run — so it survives
+    /// navigation instead of needing to be reinstalled via [`Self::evaluate`]
+    /// after each load. Backed by CDP's
+    /// `Page.addScriptToEvaluateOnNewDocument` on the chromiumoxide engine.
+    ///
+    /// The default implementation reports this adapter as not supporting
+    /// document-start scripts.
+    async fn add_script_on_new_document(&self, script: &str) -> Result<ScriptHandle, EngineError> {
+        let _ = script;
+        Err(EngineError::Browser(format!(
+            "{} adapter does not support document-start scripts",
+            self.engine_type()
+        )))
+    }
+
+    /// Remove a document-start script previously registered with
+    /// [`Self::add_script_on_new_document`].
+    ///
+    /// The default implementation reports this adapter as not supporting
+    /// document-start scripts.
+    async fn remove_script_on_new_document(
+        &self,
+        handle: &ScriptHandle,
+    ) -> Result<(), EngineError> {
+        let _ = handle;
+        Err(EngineError::Browser(format!(
+            "{} adapter does not support document-start scripts",
+            self.engine_type()
+        )))
+    }
+
     /// Bring the page to front.
     async fn bring_to_front(&self) -> Result<(), EngineError>;
 
     /// Wait for navigation to complete.
     async fn wait_for_navigation(&self, timeout_ms: u64) -> Result<(), EngineError>;
+
+    /// Subscribe to navigation lifecycle events.
+    ///
+    /// The default implementation returns an immediately-empty stream,
+    /// signalling that this adapter has no event-based navigation
+    /// monitoring. Callers should treat an empty stream as "unsupported"
+    /// and fall back to polling `url()` directly.
+    fn navigation_events(&self) -> Pin<Box<dyn Stream<Item = NavEvent> + Send>> {
+        Box::pin(stream::empty())
+    }
+
+    /// Subscribe to page activity events: in-flight network requests,
+    /// console messages, and uncaught exceptions.
+    ///
+    /// This backs true `NetworkIdle` detection and page diagnostics
+    /// capture. The default implementation returns an immediately-empty
+    /// stream, meaning this adapter reports no activity and network-idle
+    /// waits degrade to "idle from the start".
+    fn page_activity(&self) -> Pin<Box<dyn Stream<Item = PageActivityEvent> + Send>> {
+        Box::pin(stream::empty())
+    }
+
+    /// Subscribe to structured navigation lifecycle events: started,
+    /// committed, DOMContentLoaded, load-finished, and failed-with-reason,
+    /// each carrying the navigating URL, a per-navigation id, and whether
+    /// it was a same-document (history/hash) navigation.
+    ///
+    /// This is a richer complement to [`EngineAdapter::navigation_events`]
+    /// for callers that need to tell distinct navigations apart (e.g. a
+    /// redirect racing an SPA route change) rather than just observing
+    /// coarse URL/load-state transitions. The default implementation
+    /// returns an immediately-empty stream, signalling that this adapter
+    /// has no lifecycle-event monitoring; callers should treat an empty
+    /// stream as "unsupported" and fall back to polling `url()` directly.
+    fn navigation_lifecycle_events(&self) -> Pin<Box<dyn Stream<Item = NavigationEvent> + Send>> {
+        Box::pin(stream::empty())
+    }
+
+    /// Expose a function named `name` on `window` that the page can call to
+    /// push structured data to Rust, backed by CDP's `Runtime.addBinding`
+    /// plus a `Runtime.bindingCalled` event stream.
+    ///
+    /// Unlike polling a sessionStorage flag with
+    /// [`crate::high_level::check_and_clear_flag`], this delivers each call
+    /// as a [`BindingEvent`] the instant it happens, with no polling-interval
+    /// latency, and carries whatever payload the page passed in (e.g. a
+    /// JSON-stringified click description).
+    ///
+    /// The default implementation returns an immediately-empty stream,
+    /// signalling that this adapter has no binding support. Callers should
+    /// treat an empty stream as "unsupported" and fall back to
+    /// `sessionStorage` polling.
+    fn expose_binding(&self, name: &str) -> Pin<Box<dyn Stream<Item = BindingEvent> + Send>> {
+        let _ = name;
+        Box::pin(stream::empty())
+    }
+
+    /// Create a new isolated browser context (separate cookie/storage jar,
+    /// the way `Target.createBrowserContext` works over CDP), for running
+    /// parallel logged-in sessions without cookie bleed.
+    ///
+    /// The default implementation reports this adapter as not supporting
+    /// context isolation. Callers should treat this as "unsupported" rather
+    /// than a transient failure.
+    async fn create_context(&self) -> Result<ContextId, EngineError> {
+        Err(EngineError::Browser(format!(
+            "{} adapter does not support isolated contexts",
+            self.engine_type()
+        )))
+    }
+
+    /// Get an adapter scoped to a context previously created with
+    /// [`EngineAdapter::create_context`].
+    async fn adapter_for_context(
+        &self,
+        context: &ContextId,
+    ) -> Result<Box<dyn EngineAdapter>, EngineError> {
+        let _ = context;
+        Err(EngineError::Browser(format!(
+            "{} adapter does not support isolated contexts",
+            self.engine_type()
+        )))
+    }
+
+    /// Tear down a context previously created with
+    /// [`EngineAdapter::create_context`].
+    async fn dispose_context(&self, context: &ContextId) -> Result<(), EngineError> {
+        let _ = context;
+        Err(EngineError::Browser(format!(
+            "{} adapter does not support isolated contexts",
+            self.engine_type()
+        )))
+    }
+
+    /// Issue a raw Chrome DevTools Protocol command, for CDP domains/methods
+    /// the trait doesn't expose directly (e.g. setting geolocation,
+    /// emulating network conditions).
+    ///
+    /// The default implementation reports this adapter as not speaking CDP.
+    /// Only a Chromiumoxide-backed adapter is expected to override this.
+    async fn execute_cdp(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value, EngineError> {
+        let _ = (method, params);
+        Err(EngineError::Browser(format!(
+            "{} adapter does not support raw CDP commands",
+            self.engine_type()
+        )))
+    }
+
+    /// Get the text of the currently open JavaScript dialog (`alert`,
+    /// `confirm`, `prompt`, or `beforeunload`).
+    ///
+    /// The default implementation reports this adapter as not supporting
+    /// dialog handling.
+    async fn get_alert_text(&self) -> Result<String, EngineError> {
+        Err(EngineError::Browser(format!(
+            "{} adapter does not support dialog handling",
+            self.engine_type()
+        )))
+    }
+
+    /// Accept the currently open JavaScript dialog (equivalent to clicking
+    /// "OK").
+    ///
+    /// The default implementation reports this adapter as not supporting
+    /// dialog handling.
+    async fn accept_alert(&self) -> Result<(), EngineError> {
+        Err(EngineError::Browser(format!(
+            "{} adapter does not support dialog handling",
+            self.engine_type()
+        )))
+    }
+
+    /// Dismiss the currently open JavaScript dialog (equivalent to clicking
+    /// "Cancel").
+    ///
+    /// The default implementation reports this adapter as not supporting
+    /// dialog handling.
+    async fn dismiss_alert(&self) -> Result<(), EngineError> {
+        Err(EngineError::Browser(format!(
+            "{} adapter does not support dialog handling",
+            self.engine_type()
+        )))
+    }
+
+    /// Type `keys` into the currently open `prompt` dialog before it is
+    /// accepted.
+    ///
+    /// The default implementation reports this adapter as not supporting
+    /// dialog handling.
+    async fn send_alert_text(&self, keys: &str) -> Result<(), EngineError> {
+        let _ = keys;
+        Err(EngineError::Browser(format!(
+            "{} adapter does not support dialog handling",
+            self.engine_type()
+        )))
+    }
+
+    /// Set the browser window's size and/or position.
+    ///
+    /// Each field is independent: `Some` values are applied, `None` values
+    /// are left untouched. For the fantoccini/WebDriver engine this is the
+    /// W3C Set Window Rect command; a chromiumoxide-backed adapter would
+    /// instead need these baked into the `--window-size`/`--window-position`
+    /// launch arguments, since CDP has no equivalent post-launch call.
+    ///
+    /// The default implementation reports this adapter as not supporting
+    /// window geometry control.
+    async fn set_window_rect(
+        &self,
+        width: Option<u32>,
+        height: Option<u32>,
+        x: Option<i32>,
+        y: Option<i32>,
+    ) -> Result<(), EngineError> {
+        let _ = (width, height, x, y);
+        Err(EngineError::Browser(format!(
+            "{} adapter does not support window geometry control",
+            self.engine_type()
+        )))
+    }
+
+    /// Maximize the browser window.
+    ///
+    /// The default implementation reports this adapter as not supporting
+    /// window geometry control.
+    async fn maximize_window(&self) -> Result<(), EngineError> {
+        Err(EngineError::Browser(format!(
+            "{} adapter does not support window geometry control",
+            self.engine_type()
+        )))
+    }
+
+    /// Override the page's effective viewport size and device scale factor,
+    /// the way CDP's `Emulation.setDeviceMetricsOverride` does.
+    ///
+    /// The default implementation reports this adapter as not supporting
+    /// device metrics overrides. Only a Chromiumoxide-backed adapter is
+    /// expected to override this.
+    async fn set_device_metrics(
+        &self,
+        width: u32,
+        height: u32,
+        device_scale_factor: f64,
+    ) -> Result<(), EngineError> {
+        let _ = (width, height, device_scale_factor);
+        Err(EngineError::Browser(format!(
+            "{} adapter does not support device metrics overrides",
+            self.engine_type()
+        )))
+    }
+
+    /// Issue a raw WebDriver command against the adapter's session, for
+    /// endpoints the trait doesn't expose directly (e.g. custom
+    /// capabilities, vendor extension commands).
+    ///
+    /// `http_method` is a standard HTTP verb (`"GET"`, `"POST"`,
+    /// `"DELETE"`, ...) and `endpoint` is the path relative to the current
+    /// session (e.g. `"window/rect"`, not including `session/{id}/`).
+    ///
+    /// The default implementation reports this adapter as not speaking
+    /// WebDriver. Only a WebDriver-backed adapter is expected to override
+    /// this.
+    async fn issue_webdriver(
+        &self,
+        http_method: &str,
+        endpoint: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, EngineError> {
+        let _ = (http_method, endpoint, body);
+        Err(EngineError::Browser(format!(
+            "{} adapter does not support raw WebDriver commands",
+            self.engine_type()
+        )))
+    }
+}
+
+/// Wraps an [`EngineAdapter`] future with a deadline, translating an elapsed
+/// timeout into `EngineError::Timeout` that names the operation and the
+/// duration that was exceeded.
+async fn with_timeout<T>(
+    duration: Duration,
+    operation: &str,
+    fut: impl Future<Output = Result<T, EngineError>>,
+) -> Result<T, EngineError> {
+    match tokio::time::timeout(duration, fut).await {
+        Ok(result) => result,
+        Err(_) => Err(EngineError::Timeout(format!(
+            "{operation} exceeded {duration:?}"
+        ))),
+    }
+}
+
+/// Decorator that enforces the [`Timing`] budget around every call to an
+/// inner [`EngineAdapter`], so a hung browser connection surfaces as
+/// `EngineError::Timeout` instead of hanging the caller indefinitely.
+///
+/// Navigation (`goto`, `wait_for_navigation`) is bounded by
+/// `navigation_timeout`, visibility checks by `visibility_check_timeout`,
+/// and everything else (queries, clicks, fills, content reads) by
+/// `default_timeout`. Streaming, context-management, and raw-protocol
+/// escape-hatch methods pass through to the inner adapter unbounded, since
+/// those either have their own cancellation model or no natural deadline.
+pub struct TimeoutAdapter<A> {
+    inner: A,
+    timing: Timing,
+}
+
+impl<A: EngineAdapter> TimeoutAdapter<A> {
+    /// Wrap `inner`, enforcing the durations from the global [`TIMING`]
+    /// default.
+    ///
+    /// [`TIMING`]: crate::core::constants::TIMING
+    pub fn new(inner: A) -> Self {
+        Self {
+            inner,
+            timing: *crate::core::constants::TIMING,
+        }
+    }
+
+    /// Wrap `inner`, enforcing a caller-supplied [`Timing`] instead of the
+    /// global default, so a session can tighten or relax limits on its own.
+    pub fn with_timing(inner: A, timing: Timing) -> Self {
+        Self { inner, timing }
+    }
+}
+
+#[async_trait]
+impl<A: EngineAdapter> EngineAdapter for TimeoutAdapter<A> {
+    fn engine_type(&self) -> EngineType {
+        self.inner.engine_type()
+    }
+
+    async fn url(&self) -> Result<String, EngineError> {
+        with_timeout(self.timing.default_timeout, "url", self.inner.url()).await
+    }
+
+    async fn goto(&self, url: &str) -> Result<(), EngineError> {
+        with_timeout(self.timing.navigation_timeout, "goto", self.inner.goto(url)).await
+    }
+
+    async fn query_selector(&self, selector: &str) -> Result<Option<ElementInfo>, EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "query_selector",
+            self.inner.query_selector(selector),
+        )
+        .await
+    }
+
+    async fn query_selector_all(&self, selector: &str) -> Result<Vec<ElementInfo>, EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "query_selector_all",
+            self.inner.query_selector_all(selector),
+        )
+        .await
+    }
+
+    async fn count(&self, selector: &str) -> Result<usize, EngineError> {
+        with_timeout(self.timing.default_timeout, "count", self.inner.count(selector)).await
+    }
+
+    async fn click(&self, selector: &str) -> Result<(), EngineError> {
+        with_timeout(self.timing.default_timeout, "click", self.inner.click(selector)).await
+    }
+
+    async fn fill(&self, selector: &str, text: &str) -> Result<(), EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "fill",
+            self.inner.fill(selector, text),
+        )
+        .await
+    }
+
+    async fn type_text(&self, selector: &str, text: &str) -> Result<(), EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "type_text",
+            self.inner.type_text(selector, text),
+        )
+        .await
+    }
+
+    async fn text_content(&self, selector: &str) -> Result<Option<String>, EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "text_content",
+            self.inner.text_content(selector),
+        )
+        .await
+    }
+
+    async fn input_value(&self, selector: &str) -> Result<Option<String>, EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "input_value",
+            self.inner.input_value(selector),
+        )
+        .await
+    }
+
+    async fn get_attribute(
+        &self,
+        selector: &str,
+        attribute: &str,
+    ) -> Result<Option<String>, EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "get_attribute",
+            self.inner.get_attribute(selector, attribute),
+        )
+        .await
+    }
+
+    async fn is_visible(&self, selector: &str) -> Result<bool, EngineError> {
+        with_timeout(
+            self.timing.visibility_check_timeout,
+            "is_visible",
+            self.inner.is_visible(selector),
+        )
+        .await
+    }
+
+    async fn is_enabled(&self, selector: &str) -> Result<bool, EngineError> {
+        with_timeout(
+            self.timing.visibility_check_timeout,
+            "is_enabled",
+            self.inner.is_enabled(selector),
+        )
+        .await
+    }
+
+    async fn wait_for_selector(
+        &self,
+        selector: &str,
+        timeout_ms: u64,
+    ) -> Result<(), EngineError> {
+        // The caller already supplies an explicit deadline; back it with the
+        // default timeout only as a safety net against an adapter that fails
+        // to honor `timeout_ms` on a hung connection.
+        let backstop = self.timing.default_timeout.max(Duration::from_millis(timeout_ms));
+        with_timeout(
+            backstop,
+            "wait_for_selector",
+            self.inner.wait_for_selector(selector, timeout_ms),
+        )
+        .await
+    }
+
+    async fn scroll_into_view(
+        &self,
+        selector: &str,
+        alignment: ScrollAlignment,
+    ) -> Result<(), EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "scroll_into_view",
+            self.inner.scroll_into_view(selector, alignment),
+        )
+        .await
+    }
+
+    async fn evaluate(&self, script: &str) -> Result<serde_json::Value, EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "evaluate",
+            self.inner.evaluate(script),
+        )
+        .await
+    }
+
+    async fn evaluate_async(
+        &self,
+        script: &str,
+        timeout_ms: u64,
+    ) -> Result<serde_json::Value, EngineError> {
+        // `timeout_ms` is the caller's own deadline for the script to
+        // invoke its completion callback; back it with the default timeout
+        // only as a safety net against an adapter that fails to honor it.
+        let backstop = self.timing.default_timeout.max(Duration::from_millis(timeout_ms));
+        with_timeout(
+            backstop,
+            "evaluate_async",
+            self.inner.evaluate_async(script, timeout_ms),
+        )
+        .await
+    }
+
+    async fn screenshot(&self) -> Result<Vec<u8>, EngineError> {
+        with_timeout(self.timing.default_timeout, "screenshot", self.inner.screenshot()).await
+    }
+
+    async fn page_source(&self) -> Result<String, EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "page_source",
+            self.inner.page_source(),
+        )
+        .await
+    }
+
+    async fn outer_html(&self, selector: &str) -> Result<Option<String>, EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "outer_html",
+            self.inner.outer_html(selector),
+        )
+        .await
+    }
+
+    async fn sticky_viewport_offsets(&self) -> Result<ViewportOffsets, EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "sticky_viewport_offsets",
+            self.inner.sticky_viewport_offsets(),
+        )
+        .await
+    }
+
+    async fn hit_test_occlusion(&self, selector: &str) -> Result<OcclusionInfo, EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "hit_test_occlusion",
+            self.inner.hit_test_occlusion(selector),
+        )
+        .await
+    }
+
+    async fn apply_scroll_snap(&self, selector: &str) -> Result<ScrollSnapInfo, EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "apply_scroll_snap",
+            self.inner.apply_scroll_snap(selector),
+        )
+        .await
+    }
+
+    async fn intersection_ratio(&self, selector: &str) -> Result<f64, EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "intersection_ratio",
+            self.inner.intersection_ratio(selector),
+        )
+        .await
+    }
+
+    async fn is_bounding_box_stable(
+        &self,
+        selector: &str,
+        timeout_ms: u64,
+    ) -> Result<bool, EngineError> {
+        let backstop = self.timing.default_timeout.max(Duration::from_millis(timeout_ms));
+        with_timeout(
+            backstop,
+            "is_bounding_box_stable",
+            self.inner.is_bounding_box_stable(selector, timeout_ms),
+        )
+        .await
+    }
+
+    async fn find_by_text(
+        &self,
+        text: &str,
+        case_insensitive: bool,
+        whole_word: bool,
+        prefix: Option<&str>,
+        suffix: Option<&str>,
+        highlight: bool,
+    ) -> Result<TextMatchInfo, EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "find_by_text",
+            self.inner
+                .find_by_text(text, case_insensitive, whole_word, prefix, suffix, highlight),
+        )
+        .await
+    }
+
+    async fn add_script_on_new_document(&self, script: &str) -> Result<ScriptHandle, EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "add_script_on_new_document",
+            self.inner.add_script_on_new_document(script),
+        )
+        .await
+    }
+
+    async fn remove_script_on_new_document(
+        &self,
+        handle: &ScriptHandle,
+    ) -> Result<(), EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "remove_script_on_new_document",
+            self.inner.remove_script_on_new_document(handle),
+        )
+        .await
+    }
+
+    async fn bring_to_front(&self) -> Result<(), EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "bring_to_front",
+            self.inner.bring_to_front(),
+        )
+        .await
+    }
+
+    async fn wait_for_navigation(&self, timeout_ms: u64) -> Result<(), EngineError> {
+        let backstop = self
+            .timing
+            .navigation_timeout
+            .max(Duration::from_millis(timeout_ms));
+        with_timeout(
+            backstop,
+            "wait_for_navigation",
+            self.inner.wait_for_navigation(timeout_ms),
+        )
+        .await
+    }
+
+    fn navigation_events(&self) -> Pin<Box<dyn Stream<Item = NavEvent> + Send>> {
+        self.inner.navigation_events()
+    }
+
+    fn page_activity(&self) -> Pin<Box<dyn Stream<Item = PageActivityEvent> + Send>> {
+        self.inner.page_activity()
+    }
+
+    fn navigation_lifecycle_events(&self) -> Pin<Box<dyn Stream<Item = NavigationEvent> + Send>> {
+        self.inner.navigation_lifecycle_events()
+    }
+
+    fn expose_binding(&self, name: &str) -> Pin<Box<dyn Stream<Item = BindingEvent> + Send>> {
+        self.inner.expose_binding(name)
+    }
+
+    async fn create_context(&self) -> Result<ContextId, EngineError> {
+        self.inner.create_context().await
+    }
+
+    async fn adapter_for_context(
+        &self,
+        context: &ContextId,
+    ) -> Result<Box<dyn EngineAdapter>, EngineError> {
+        self.inner.adapter_for_context(context).await
+    }
+
+    async fn dispose_context(&self, context: &ContextId) -> Result<(), EngineError> {
+        self.inner.dispose_context(context).await
+    }
+
+    async fn execute_cdp(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value, EngineError> {
+        self.inner.execute_cdp(method, params).await
+    }
+
+    async fn issue_webdriver(
+        &self,
+        http_method: &str,
+        endpoint: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<serde_json::Value, EngineError> {
+        self.inner.issue_webdriver(http_method, endpoint, body).await
+    }
+
+    async fn get_alert_text(&self) -> Result<String, EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "get_alert_text",
+            self.inner.get_alert_text(),
+        )
+        .await
+    }
+
+    async fn accept_alert(&self) -> Result<(), EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "accept_alert",
+            self.inner.accept_alert(),
+        )
+        .await
+    }
+
+    async fn dismiss_alert(&self) -> Result<(), EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "dismiss_alert",
+            self.inner.dismiss_alert(),
+        )
+        .await
+    }
+
+    async fn send_alert_text(&self, keys: &str) -> Result<(), EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "send_alert_text",
+            self.inner.send_alert_text(keys),
+        )
+        .await
+    }
+
+    async fn set_window_rect(
+        &self,
+        width: Option<u32>,
+        height: Option<u32>,
+        x: Option<i32>,
+        y: Option<i32>,
+    ) -> Result<(), EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "set_window_rect",
+            self.inner.set_window_rect(width, height, x, y),
+        )
+        .await
+    }
+
+    async fn maximize_window(&self) -> Result<(), EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "maximize_window",
+            self.inner.maximize_window(),
+        )
+        .await
+    }
+
+    async fn set_device_metrics(
+        &self,
+        width: u32,
+        height: u32,
+        device_scale_factor: f64,
+    ) -> Result<(), EngineError> {
+        with_timeout(
+            self.timing.default_timeout,
+            "set_device_metrics",
+            self.inner.set_device_metrics(width, height, device_scale_factor),
+        )
+        .await
+    }
+}
+
+/// An observed event on the page: network request lifecycle, console
+/// output, an uncaught exception, or a value pushed from the page through
+/// an exposed binding.
+///
+/// Emitted by [`EngineAdapter::page_activity`], mirroring the CDP
+/// `Network.requestWillBeSent`/`responseReceived`/`loadingFinished`/
+/// `loadingFailed`, `Runtime.consoleAPICalled`/`exceptionThrown`, and
+/// `Runtime.bindingCalled` events.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PageActivityEvent {
+    /// A network request started.
+    RequestStarted,
+    /// A network request finished successfully.
+    RequestFinished,
+    /// A network request failed (network error, not an HTTP status).
+    RequestFailed,
+    /// A response was received for a request.
+    ResponseReceived {
+        /// The request URL.
+        url: String,
+        /// The HTTP status code. CDP reports `0` for a request that never
+        /// completed (e.g. aborted by navigation), rather than a real HTTP
+        /// status.
+        status: u16,
+    },
+    /// A console API call (e.g. `console.log`, `console.error`).
+    ConsoleApiCalled {
+        /// The console method used (`log`, `warn`, `error`, ...).
+        level: String,
+        /// The stringified arguments passed to the call.
+        args: Vec<String>,
+    },
+    /// An uncaught exception was thrown on the page.
+    ExceptionThrown {
+        /// The exception's message.
+        text: String,
+        /// The exception's stack trace, if available.
+        stack: Option<String>,
+    },
+    /// A page-exposed binding was called from page script (see
+    /// `Runtime.addBinding`/`Runtime.bindingCalled`).
+    BindingCalled {
+        /// The binding's name, as passed to `addBinding`.
+        name: String,
+        /// The raw payload the page passed to the binding call.
+        payload: String,
+    },
+}
+
+/// A discrete navigation lifecycle transition.
+///
+/// Emitted by [`EngineAdapter::navigation_events`] so callers can detect
+/// redirects, load-state transitions, and error pages without polling
+/// `url()` on a fixed interval.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NavEvent {
+    /// The page URL changed (including same-document/history navigations).
+    UrlChanged(String),
+    /// The page reached a new load state.
+    LoadStateChanged(LoadState),
+    /// The page type changed, e.g. loading an error document.
+    PageTypeChanged {
+        /// Whether the current page is an error page.
+        error: bool,
+    },
+    /// The navigation was committed (a new document started loading).
+    NavigationCommitted,
+}
+
+/// Page load states reported by [`NavEvent::LoadStateChanged`].
+///
+/// This mirrors `browser::navigation_ops::WaitUntil` but lives in `core` so
+/// the engine layer does not depend on the higher-level navigation module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoadState {
+    /// The DOMContentLoaded event fired.
+    DomContentLoaded,
+    /// The load event fired.
+    Load,
+    /// The network has been idle for the configured quiet window.
+    NetworkIdle,
+}
+
+/// Identifies a single navigation attempt, so multiple in-flight
+/// navigations (e.g. an SPA route change racing a full-page redirect) can
+/// be told apart in a [`NavigationEvent`] stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NavigationId(pub u64);
+
+/// A lifecycle transition for a single navigation.
+///
+/// Mirrors the phases an embedder observes for a real navigation: it
+/// starts, gets committed to a new URL, reaches `DOMContentLoaded` and then
+/// `load`, or fails outright with a reason.
+#[derive(Debug, Clone, PartialEq)]
+pub enum NavigationPhase {
+    /// The navigation started (the URL began changing).
+    Started,
+    /// The navigation was committed to its target URL.
+    Committed,
+    /// The `DOMContentLoaded` event fired.
+    DomContentLoaded,
+    /// The `load` event fired; the navigation is complete.
+    LoadFinished,
+    /// The navigation failed, with a diagnostic reason.
+    Failed(String),
+}
+
+/// A structured navigation lifecycle event, emitted by
+/// [`EngineAdapter::navigation_lifecycle_events`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct NavigationEvent {
+    /// Identifies which navigation this event belongs to.
+    pub navigation_id: NavigationId,
+    /// The URL the navigation is headed to (or the last known URL, for a
+    /// [`NavigationPhase::Failed`] event).
+    pub url: String,
+    /// Whether this was a same-document navigation (history/hash change)
+    /// rather than a full document load.
+    pub same_document: bool,
+    /// Which lifecycle phase this event reports.
+    pub phase: NavigationPhase,
 }
 
 #[cfg(test)]
@@ -287,4 +1747,220 @@ mod tests {
         assert!(result.verified);
         assert!(!result.navigation_error);
     }
+
+    #[test]
+    fn nav_event_equality() {
+        assert_eq!(
+            NavEvent::UrlChanged("https://example.com".to_string()),
+            NavEvent::UrlChanged("https://example.com".to_string())
+        );
+        assert_ne!(
+            NavEvent::PageTypeChanged { error: true },
+            NavEvent::PageTypeChanged { error: false }
+        );
+    }
+
+    #[tokio::test]
+    async fn default_navigation_events_stream_is_empty() {
+        use futures::StreamExt;
+
+        let adapter = SleepyAdapter {
+            sleep_for: Duration::ZERO,
+        };
+        let mut events = adapter.navigation_events();
+        assert!(events.next().await.is_none());
+    }
+
+    #[test]
+    fn navigation_event_equality() {
+        let event = NavigationEvent {
+            navigation_id: NavigationId(1),
+            url: "https://example.com".to_string(),
+            same_document: false,
+            phase: NavigationPhase::Started,
+        };
+        assert_eq!(event.clone(), event);
+        assert_ne!(
+            event,
+            NavigationEvent {
+                phase: NavigationPhase::Committed,
+                ..event
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn default_navigation_lifecycle_events_stream_is_empty() {
+        use futures::StreamExt;
+
+        let adapter = SleepyAdapter {
+            sleep_for: Duration::ZERO,
+        };
+        let mut events = adapter.navigation_lifecycle_events();
+        assert!(events.next().await.is_none());
+    }
+
+    #[test]
+    fn context_id_equality() {
+        assert_eq!(ContextId("abc".to_string()), ContextId("abc".to_string()));
+        assert_ne!(ContextId("abc".to_string()), ContextId("def".to_string()));
+    }
+
+    #[test]
+    fn page_activity_event_equality() {
+        assert_eq!(
+            PageActivityEvent::ResponseReceived {
+                url: "https://example.com".to_string(),
+                status: 404,
+            },
+            PageActivityEvent::ResponseReceived {
+                url: "https://example.com".to_string(),
+                status: 404,
+            }
+        );
+        assert_ne!(PageActivityEvent::RequestStarted, PageActivityEvent::RequestFinished);
+    }
+
+    /// Minimal adapter that sleeps for a fixed duration before returning the
+    /// current "url", used to exercise [`TimeoutAdapter`] without a real
+    /// browser backend.
+    struct SleepyAdapter {
+        sleep_for: Duration,
+    }
+
+    #[async_trait]
+    impl EngineAdapter for SleepyAdapter {
+        fn engine_type(&self) -> EngineType {
+            EngineType::Fantoccini
+        }
+
+        async fn url(&self) -> Result<String, EngineError> {
+            tokio::time::sleep(self.sleep_for).await;
+            Ok("https://example.com".to_string())
+        }
+
+        async fn goto(&self, _url: &str) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn query_selector(&self, _selector: &str) -> Result<Option<ElementInfo>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn query_selector_all(&self, _selector: &str) -> Result<Vec<ElementInfo>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn count(&self, _selector: &str) -> Result<usize, EngineError> {
+            unimplemented!()
+        }
+
+        async fn click(&self, _selector: &str) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn fill(&self, _selector: &str, _text: &str) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn type_text(&self, _selector: &str, _text: &str) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn text_content(&self, _selector: &str) -> Result<Option<String>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn input_value(&self, _selector: &str) -> Result<Option<String>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn get_attribute(
+            &self,
+            _selector: &str,
+            _attribute: &str,
+        ) -> Result<Option<String>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn is_visible(&self, _selector: &str) -> Result<bool, EngineError> {
+            unimplemented!()
+        }
+
+        async fn is_enabled(&self, _selector: &str) -> Result<bool, EngineError> {
+            unimplemented!()
+        }
+
+        async fn wait_for_selector(
+            &self,
+            _selector: &str,
+            _timeout_ms: u64,
+        ) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn scroll_into_view(
+            &self,
+            _selector: &str,
+            _alignment: ScrollAlignment,
+        ) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn evaluate(&self, _script: &str) -> Result<serde_json::Value, EngineError> {
+            unimplemented!()
+        }
+
+        async fn screenshot(&self) -> Result<Vec<u8>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn bring_to_front(&self) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn wait_for_navigation(&self, _timeout_ms: u64) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn timeout_adapter_passes_through_fast_calls() {
+        let adapter = TimeoutAdapter::with_timing(
+            SleepyAdapter {
+                sleep_for: Duration::ZERO,
+            },
+            Timing {
+                default_timeout: Duration::from_millis(50),
+                ..Timing::default()
+            },
+        );
+
+        assert_eq!(adapter.url().await.unwrap(), "https://example.com");
+    }
+
+    #[tokio::test]
+    async fn timeout_adapter_times_out_slow_calls() {
+        let adapter = TimeoutAdapter::with_timing(
+            SleepyAdapter {
+                sleep_for: Duration::from_millis(50),
+            },
+            Timing {
+                default_timeout: Duration::from_millis(5),
+                ..Timing::default()
+            },
+        );
+
+        let result = adapter.url().await;
+        assert!(matches!(result, Err(EngineError::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn timeout_adapter_new_uses_global_timing_default() {
+        let adapter = TimeoutAdapter::new(SleepyAdapter {
+            sleep_for: Duration::ZERO,
+        });
+
+        assert_eq!(adapter.timing.default_timeout, crate::core::constants::TIMING.default_timeout);
+    }
 }
@@ -1,10 +1,16 @@
 //! Logger configuration for browser automation.
 //!
 //! This module provides a simple interface for creating loggers
-//! with configurable verbosity levels.
+//! with configurable verbosity and output format.
 
-use tracing::Level;
-use tracing_subscriber::{fmt, prelude::*, EnvFilter};
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::{self, FmtContext, FormatEvent, FormatFields};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{prelude::*, EnvFilter};
 
 /// Check if verbose logging is enabled via environment or CLI args.
 ///
@@ -17,11 +23,137 @@ pub fn is_verbose_enabled() -> bool {
     std::env::var("VERBOSE").is_ok() || std::env::args().any(|arg| arg == "--verbose")
 }
 
+/// The output format diagnostic events are emitted in.
+///
+/// Selects between the [`Emitter`] implementations [`Human`] (the existing
+/// colored single-line format) and [`Json`] (one self-describing object per
+/// event, for piping into log aggregators).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// Colored, human-readable single-line output.
+    #[default]
+    Human,
+    /// One JSON object per event: `{ timestamp, level, target, message, fields }`.
+    Json,
+}
+
+/// A pluggable diagnostic event emitter.
+///
+/// Analogous to rustc's `HumanReadableErrorType` vs `JsonEmitter`: each
+/// implementation knows how to build and install the global `tracing`
+/// subscriber for its format.
+pub trait Emitter {
+    /// Build the fmt layer for this emitter and install it as the global
+    /// subscriber, filtered by `filter`.
+    fn init(&self, filter: EnvFilter);
+}
+
+/// Emits colored, human-readable single-line log output.
+pub struct Human;
+
+impl Emitter for Human {
+    fn init(&self, filter: EnvFilter) {
+        let subscriber = fmt::layer().with_target(true).with_level(true);
+
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(subscriber)
+            .try_init()
+            .ok(); // Ignore error if already initialized
+    }
+}
+
+/// Emits one self-describing JSON object per event.
+pub struct Json;
+
+impl Emitter for Json {
+    fn init(&self, filter: EnvFilter) {
+        let subscriber = fmt::layer().event_format(JsonEventFormat);
+
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(subscriber)
+            .try_init()
+            .ok(); // Ignore error if already initialized
+    }
+}
+
+/// Collects an event's fields into a JSON object, pulling `message` out
+/// separately since it gets its own top-level key in the emitted line.
+#[derive(Default)]
+struct JsonFieldVisitor {
+    message: Option<String>,
+    fields: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Visit for JsonFieldVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.record(field, format!("{value:?}"));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, value.to_string());
+    }
+}
+
+impl JsonFieldVisitor {
+    fn record(&mut self, field: &Field, value: String) {
+        if field.name() == "message" {
+            self.message = Some(value);
+        } else {
+            self.fields
+                .insert(field.name().to_string(), serde_json::Value::String(value));
+        }
+    }
+}
+
+/// [`FormatEvent`] implementation backing [`Json`], producing
+/// `{ timestamp, level, target, message, fields }` objects.
+struct JsonEventFormat;
+
+impl<S, N> FormatEvent<S, N> for JsonEventFormat
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        _ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let mut visitor = JsonFieldVisitor::default();
+        event.record(&mut visitor);
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs_f64())
+            .unwrap_or(0.0);
+
+        let line = serde_json::json!({
+            "timestamp": timestamp,
+            "level": event.metadata().level().to_string(),
+            "target": event.metadata().target(),
+            "message": visitor.message.unwrap_or_default(),
+            "fields": visitor.fields,
+        });
+
+        writeln!(writer, "{line}")
+    }
+}
+
 /// Logger configuration options.
 #[derive(Debug, Clone, Default)]
 pub struct LoggerOptions {
     /// Enable verbose (debug level) logging.
     pub verbose: bool,
+    /// The output format to emit diagnostic events in.
+    pub format: LogFormat,
+    /// An additional `EnvFilter` directive string to merge in on top of the
+    /// verbose/non-verbose level, e.g. `"browser_commander::interactions=debug"`,
+    /// so callers can raise verbosity for specific modules without enabling
+    /// it everywhere. Invalid directives are ignored.
+    pub directive: Option<String>,
 }
 
 /// Initialize the global tracing subscriber with the given options.
@@ -37,7 +169,7 @@ pub struct LoggerOptions {
 /// ```
 /// use browser_commander::core::logger::{init_logger, LoggerOptions};
 ///
-/// init_logger(LoggerOptions { verbose: true });
+/// init_logger(LoggerOptions { verbose: true, ..Default::default() });
 /// ```
 pub fn init_logger(options: LoggerOptions) {
     let level = if options.verbose {
@@ -46,15 +178,18 @@ pub fn init_logger(options: LoggerOptions) {
         Level::ERROR
     };
 
-    let filter = EnvFilter::from_default_env().add_directive(level.into());
-
-    let subscriber = fmt::layer().with_target(true).with_level(true);
+    let mut filter = EnvFilter::from_default_env().add_directive(level.into());
+    if let Some(directive) = &options.directive {
+        if let Ok(directive) = directive.parse() {
+            filter = filter.add_directive(directive);
+        }
+    }
 
-    tracing_subscriber::registry()
-        .with(filter)
-        .with(subscriber)
-        .try_init()
-        .ok(); // Ignore error if already initialized
+    let emitter: &dyn Emitter = match options.format {
+        LogFormat::Human => &Human,
+        LogFormat::Json => &Json,
+    };
+    emitter.init(filter);
 }
 
 /// A simple logger wrapper that respects verbosity settings.
@@ -149,13 +284,19 @@ mod tests {
 
     #[test]
     fn logger_can_be_created_with_verbose_true() {
-        let logger = Logger::new(LoggerOptions { verbose: true });
+        let logger = Logger::new(LoggerOptions {
+            verbose: true,
+            ..Default::default()
+        });
         assert!(logger.is_verbose());
     }
 
     #[test]
     fn logger_can_be_created_with_verbose_false() {
-        let logger = Logger::new(LoggerOptions { verbose: false });
+        let logger = Logger::new(LoggerOptions {
+            verbose: false,
+            ..Default::default()
+        });
         assert!(!logger.is_verbose());
     }
 
@@ -163,11 +304,16 @@ mod tests {
     fn logger_options_default_is_not_verbose() {
         let options = LoggerOptions::default();
         assert!(!options.verbose);
+        assert_eq!(options.format, LogFormat::Human);
+        assert!(options.directive.is_none());
     }
 
     #[test]
     fn debug_message_fn_not_called_when_not_verbose() {
-        let logger = Logger::new(LoggerOptions { verbose: false });
+        let logger = Logger::new(LoggerOptions {
+            verbose: false,
+            ..Default::default()
+        });
         let mut was_called = false;
 
         logger.debug(|| {
@@ -180,7 +326,10 @@ mod tests {
 
     #[test]
     fn debug_message_fn_called_when_verbose() {
-        let logger = Logger::new(LoggerOptions { verbose: true });
+        let logger = Logger::new(LoggerOptions {
+            verbose: true,
+            ..Default::default()
+        });
         let mut was_called = false;
 
         logger.debug(|| {
@@ -190,4 +339,9 @@ mod tests {
 
         assert!(was_called);
     }
+
+    #[test]
+    fn log_format_default_is_human() {
+        assert_eq!(LogFormat::default(), LogFormat::Human);
+    }
 }
@@ -13,10 +13,15 @@ pub mod navigation;
 
 pub use constants::{Timing, CHROME_ARGS, TIMING};
 pub use engine::{
-    ClickVerificationResult, ElementInfo, EngineAdapter, EngineError, EngineType,
-    FillVerificationResult, PreClickState, ScrollVerificationResult,
+    BindingEvent, ClickVerificationResult, ContextId, ElementInfo, EngineAdapter, EngineError,
+    EngineType, FillVerificationResult, LoadState, NavEvent, NavigationEvent, NavigationId,
+    NavigationPhase, OcclusionInfo, PageActivityEvent, PreClickState, ScriptHandle,
+    ScrollAlignment, ScrollSnapInfo, ScrollVerificationResult, TextMatchInfo, TimeoutAdapter,
+    ViewportOffsets,
 };
-pub use logger::{init_logger, is_verbose_enabled, Logger, LoggerOptions};
+pub use logger::{init_logger, is_verbose_enabled, Emitter, LogFormat, Logger, LoggerOptions};
 pub use navigation::{
-    is_navigation_error, is_timeout_error, safe_operation, NavigationError, SafeResult,
+    classify_error, is_dialog_error, is_navigation_error, is_timeout_error,
+    register_error_pattern, retry_operation, safe_operation, safe_operation_with_events,
+    NavigationError, RetryOptions, SafeResult,
 };
@@ -1,8 +1,15 @@
 //! Navigation safety utilities.
 //!
 //! This module provides utilities for handling navigation-related errors
-//! gracefully during browser automation.
+//! gracefully during browser automation: classifying the specific
+//! [`NavigationError`] an error message indicates, and retrying transient
+//! failures with backoff via [`retry_operation`].
 
+use crate::core::engine::{EngineAdapter, EngineError, PageActivityEvent};
+use futures::{FutureExt, StreamExt};
+use rand::Rng;
+use std::sync::{LazyLock, RwLock};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
 /// Errors related to navigation operations.
@@ -29,28 +36,144 @@ pub enum NavigationError {
     ExecutionContextDestroyed,
 }
 
-/// Common error messages that indicate a navigation error.
-const NAVIGATION_ERROR_PATTERNS: &[&str] = &[
-    "navigat", // Matches "navigation", "navigated", etc.
-    "detached",
-    "context was destroyed",
-    "execution context was destroyed",
-    "frame was detached",
-    "target closed",
-    "page has been closed",
-    "session closed",
-    "cannot find context",
-    "protocol error",
-];
+/// A classification rule mapping a substring (matched case-insensitively)
+/// to the specific [`NavigationError`] variant it indicates.
+///
+/// `classify` receives the full error message so variants that carry data
+/// (like [`NavigationError::Interrupted`] or [`NavigationError::Timeout`])
+/// can be built from it.
+#[derive(Clone, Copy)]
+struct ErrorPattern {
+    pattern: &'static str,
+    classify: fn(&str) -> NavigationError,
+}
 
-/// Common error messages that indicate a timeout error.
-const TIMEOUT_ERROR_PATTERNS: &[&str] = &["timed out", "timeout", "exceeded", "waiting for"];
+/// Pull the first run of digits out of a timeout message, e.g. `30000` out
+/// of `"Navigation timed out after 30000ms"`. Falls back to `0` when the
+/// message carries no duration (e.g. `"Timeout exceeded"`).
+fn parse_timeout_ms(message: &str) -> u64 {
+    message
+        .split(|c: char| !c.is_ascii_digit())
+        .find_map(|token| token.parse().ok())
+        .unwrap_or(0)
+}
+
+/// The built-in classification rules, checked in order (most specific
+/// first) before any patterns registered via [`register_error_pattern`].
+fn default_patterns() -> Vec<ErrorPattern> {
+    vec![
+        ErrorPattern {
+            pattern: "timed out",
+            classify: |m| NavigationError::Timeout(parse_timeout_ms(m)),
+        },
+        ErrorPattern {
+            pattern: "timeout",
+            classify: |m| NavigationError::Timeout(parse_timeout_ms(m)),
+        },
+        ErrorPattern {
+            pattern: "exceeded",
+            classify: |m| NavigationError::Timeout(parse_timeout_ms(m)),
+        },
+        ErrorPattern {
+            pattern: "waiting for",
+            classify: |m| NavigationError::Timeout(parse_timeout_ms(m)),
+        },
+        ErrorPattern {
+            pattern: "target closed",
+            classify: |_| NavigationError::TargetDetached,
+        },
+        ErrorPattern {
+            pattern: "detached",
+            classify: |_| NavigationError::TargetDetached,
+        },
+        ErrorPattern {
+            pattern: "execution context was destroyed",
+            classify: |_| NavigationError::ExecutionContextDestroyed,
+        },
+        ErrorPattern {
+            pattern: "context was destroyed",
+            classify: |_| NavigationError::ExecutionContextDestroyed,
+        },
+        ErrorPattern {
+            pattern: "cannot find context",
+            classify: |_| NavigationError::ExecutionContextDestroyed,
+        },
+        ErrorPattern {
+            pattern: "session closed",
+            classify: |_| NavigationError::TargetDetached,
+        },
+        ErrorPattern {
+            pattern: "page has been closed",
+            classify: |_| NavigationError::TargetDetached,
+        },
+        ErrorPattern {
+            pattern: "protocol error",
+            classify: |_| NavigationError::TargetDetached,
+        },
+        ErrorPattern {
+            pattern: "interrupted",
+            classify: |m| NavigationError::Interrupted(m.to_string()),
+        },
+        ErrorPattern {
+            pattern: "navigat", // Matches "navigation", "navigated", etc.
+            classify: |_| NavigationError::PageNavigatedAway,
+        },
+    ]
+}
+
+/// Runtime-registered classification rules, consulted after the built-ins
+/// so engine adapters with different wording (e.g. WebDriver vs CDP) can
+/// extend classification without a code change here.
+static PATTERN_REGISTRY: LazyLock<RwLock<Vec<ErrorPattern>>> =
+    LazyLock::new(|| RwLock::new(default_patterns()));
+
+/// Register an additional `(pattern, variant)` classification rule.
+///
+/// `pattern` is matched as a case-insensitive substring of the error
+/// message; `classify` builds the [`NavigationError`] to report on a match.
+/// Registered patterns are consulted in registration order, after the
+/// built-in ones.
+///
+/// # Arguments
+///
+/// * `pattern` - The case-insensitive substring to match
+/// * `classify` - Builds the variant to report, given the full message
+pub fn register_error_pattern(pattern: &'static str, classify: fn(&str) -> NavigationError) {
+    PATTERN_REGISTRY
+        .write()
+        .expect("pattern registry poisoned")
+        .push(ErrorPattern { pattern, classify });
+}
+
+/// Classify an error message into the specific [`NavigationError`] variant
+/// it indicates.
+///
+/// Consults the built-in patterns first (most specific first), then any
+/// registered via [`register_error_pattern`], and returns the first match.
+///
+/// # Arguments
+///
+/// * `error_message` - The error message to classify
+///
+/// # Returns
+///
+/// The matched [`NavigationError`], or `None` if no pattern matched
+pub fn classify_error(error_message: &str) -> Option<NavigationError> {
+    let lower = error_message.to_lowercase();
+    PATTERN_REGISTRY
+        .read()
+        .expect("pattern registry poisoned")
+        .iter()
+        .find(|p| lower.contains(&p.pattern.to_lowercase()))
+        .map(|p| (p.classify)(error_message))
+}
 
 /// Check if an error message indicates a navigation error.
 ///
 /// Navigation errors are expected during browser automation when pages
 /// navigate away during operations. These errors should generally be
-/// handled gracefully.
+/// handled gracefully. Equivalent to [`classify_error`] matching something
+/// other than [`NavigationError::Timeout`].
 ///
 /// # Arguments
 ///
@@ -60,15 +183,13 @@ const TIMEOUT_ERROR_PATTERNS: &[&str] = &["timed out", "timeout", "exceeded", "w
 ///
 /// `true` if the error appears to be a navigation error
 pub fn is_navigation_error(error_message: &str) -> bool {
-    NAVIGATION_ERROR_PATTERNS.iter().any(|pattern| {
-        error_message
-            .to_lowercase()
-            .contains(&pattern.to_lowercase())
-    })
+    matches!(classify_error(error_message), Some(e) if !matches!(e, NavigationError::Timeout(_)))
 }
 
 /// Check if an error message indicates a timeout error.
 ///
+/// Equivalent to [`classify_error`] matching [`NavigationError::Timeout`].
+///
 /// # Arguments
 ///
 /// * `error_message` - The error message to check
@@ -77,11 +198,43 @@ pub fn is_navigation_error(error_message: &str) -> bool {
 ///
 /// `true` if the error appears to be a timeout error
 pub fn is_timeout_error(error_message: &str) -> bool {
-    TIMEOUT_ERROR_PATTERNS.iter().any(|pattern| {
-        error_message
-            .to_lowercase()
-            .contains(&pattern.to_lowercase())
-    })
+    matches!(classify_error(error_message), Some(NavigationError::Timeout(_)))
+}
+
+/// Check if an error message indicates an open, unhandled JavaScript dialog
+/// (`alert`/`confirm`/`prompt`/`beforeunload`) blocking the requested
+/// operation.
+///
+/// This mirrors the W3C WebDriver `unexpected alert open` error, which most
+/// commands return while a dialog is showing, so callers like
+/// [`crate::utilities::wait::safe_evaluate`] can tell a pending dialog apart
+/// from a real navigation or evaluation failure.
+///
+/// # Arguments
+///
+/// * `error_message` - The error message to check
+///
+/// # Returns
+///
+/// `true` if the error appears to indicate an open dialog
+pub fn is_dialog_error(error_message: &str) -> bool {
+    error_message.to_lowercase().contains("unexpected alert open")
+}
+
+/// Whether a buffered [`PageActivityEvent`] should itself be treated as a
+/// navigation error, even though the adapter call it occurred during
+/// returned `Ok`.
+///
+/// This catches page-side failures an adapter error never surfaces: an
+/// uncaught exception tearing down the execution context (classified via
+/// the same patterns as [`is_navigation_error`]), or a response that never
+/// completed (CDP reports these with `status: 0`, not a real HTTP status).
+fn is_error_event(event: &PageActivityEvent) -> bool {
+    match event {
+        PageActivityEvent::ExceptionThrown { text, .. } => is_navigation_error(text),
+        PageActivityEvent::ResponseReceived { status, .. } => *status == 0,
+        _ => false,
+    }
 }
 
 /// Execute an operation with navigation safety.
@@ -118,6 +271,19 @@ pub struct SafeResult<T> {
     pub success: bool,
     /// Whether a navigation error occurred.
     pub navigation_error: bool,
+    /// Page activity events captured while the operation ran (empty unless
+    /// produced by [`safe_operation_with_events`]).
+    pub events: Vec<PageActivityEvent>,
+    /// The specific transient error [`retry_operation`] last observed
+    /// before giving up (`None` outside of [`retry_operation`], or on
+    /// success).
+    pub final_error: Option<NavigationError>,
+    /// Number of attempts [`retry_operation`] made, including the first
+    /// (`1` outside of [`retry_operation`]).
+    pub attempts: u32,
+    /// Total time [`retry_operation`] spent across all attempts and
+    /// backoff delays (`Duration::ZERO` outside of [`retry_operation`]).
+    pub elapsed: Duration,
 }
 
 impl<T: Default> SafeResult<T> {
@@ -127,6 +293,10 @@ impl<T: Default> SafeResult<T> {
             value,
             success: true,
             navigation_error: false,
+            events: Vec::new(),
+            final_error: None,
+            attempts: 1,
+            elapsed: Duration::ZERO,
         }
     }
 
@@ -136,6 +306,10 @@ impl<T: Default> SafeResult<T> {
             value: T::default(),
             success: false,
             navigation_error: true,
+            events: Vec::new(),
+            final_error: None,
+            attempts: 1,
+            elapsed: Duration::ZERO,
         }
     }
 
@@ -145,6 +319,183 @@ impl<T: Default> SafeResult<T> {
             value,
             success: false,
             navigation_error: false,
+            events: Vec::new(),
+            final_error: None,
+            attempts: 1,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Attach captured page activity events to this result.
+    pub fn with_events(mut self, events: Vec<PageActivityEvent>) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// Attach [`retry_operation`] bookkeeping to this result.
+    pub fn with_retry_info(
+        mut self,
+        final_error: Option<NavigationError>,
+        attempts: u32,
+        elapsed: Duration,
+    ) -> Self {
+        self.final_error = final_error;
+        self.attempts = attempts;
+        self.elapsed = elapsed;
+        self
+    }
+}
+
+/// Execute an operation with navigation safety, additionally draining
+/// [`EngineAdapter::page_activity`] events buffered while it ran and
+/// treating an [`is_error_event`] match as a navigation error even when the
+/// operation itself returned `Ok`.
+///
+/// # Arguments
+///
+/// * `adapter` - The engine adapter whose page activity to monitor
+/// * `operation` - The async operation to execute
+/// * `default` - The value to use if a navigation error is detected
+///
+/// # Returns
+///
+/// A [`SafeResult`] carrying the outcome and any events observed
+pub async fn safe_operation_with_events<F, T>(
+    adapter: &dyn EngineAdapter,
+    operation: F,
+    default: T,
+) -> SafeResult<T>
+where
+    F: std::future::Future<Output = Result<T, EngineError>>,
+    T: Default,
+{
+    let mut activity = adapter.page_activity();
+    let result = operation.await;
+
+    let mut events = Vec::new();
+    while let Some(Some(event)) = activity.next().now_or_never() {
+        events.push(event);
+    }
+    let event_error = events.iter().any(is_error_event);
+
+    match result {
+        Ok(_) if event_error => SafeResult {
+            value: default,
+            ..SafeResult::navigation_error()
+        }
+        .with_events(events),
+        Ok(value) => SafeResult::success(value).with_events(events),
+        Err(e) if is_navigation_error(&e.to_string()) => SafeResult {
+            value: default,
+            ..SafeResult::<T>::navigation_error()
+        }
+        .with_events(events),
+        Err(_) => SafeResult::error(default).with_events(events),
+    }
+}
+
+/// Configuration for [`retry_operation`]'s exponential-backoff-with-jitter
+/// retry loop.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryOptions {
+    /// Maximum number of attempts (including the first); a still-transient
+    /// failure on the last attempt is reported as exhausted rather than
+    /// retried again.
+    pub max_attempts: u32,
+    /// Base delay for the exponential backoff (`base * 2^attempt`).
+    pub base_delay: Duration,
+    /// Upper bound the computed delay is capped to before jitter is applied.
+    pub max_delay: Duration,
+    /// Overall deadline across all attempts and delays; once elapsed meets
+    /// or exceeds this, retrying stops even if `max_attempts` hasn't been
+    /// reached.
+    pub deadline: Duration,
+}
+
+impl Default for RetryOptions {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(5),
+            deadline: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Compute the exponential-backoff-with-full-jitter delay for a 0-indexed
+/// `attempt`: `min(cap, base * 2^attempt)`, then uniformly randomized in
+/// `[0, delay]`.
+fn backoff_delay(options: &RetryOptions, attempt: u32) -> Duration {
+    let factor = 1u64.checked_shl(attempt).unwrap_or(u64::MAX);
+    let capped_ms = (options.base_delay.as_millis() as u64)
+        .saturating_mul(factor)
+        .min(options.max_delay.as_millis() as u64);
+
+    let jittered_ms = if capped_ms == 0 {
+        0
+    } else {
+        rand::thread_rng().gen_range(0..=capped_ms)
+    };
+    Duration::from_millis(jittered_ms)
+}
+
+/// Execute `operation`, retrying transient ([`classify_error`]-matched)
+/// failures with exponential backoff and full jitter (see [`backoff_delay`])
+/// until it succeeds, a permanent error occurs, or `options` exhausts
+/// (`max_attempts` reached or `deadline` elapsed).
+///
+/// A permanent error — one [`classify_error`] doesn't recognize — short-
+/// circuits immediately without retrying, since retrying it would just
+/// waste the deadline on an error that backoff can't fix.
+///
+/// # Arguments
+///
+/// * `options` - Attempt/backoff/deadline configuration
+/// * `operation` - Produces the async operation to (re)try; called once per
+///   attempt, so it must be safe to re-run (e.g. a closure re-issuing the
+///   underlying engine call)
+///
+/// # Returns
+///
+/// A [`SafeResult`] whose `final_error`, `attempts`, and `elapsed` fields
+/// let callers distinguish "gave up after N transient retries" from
+/// "failed on a real error"
+pub async fn retry_operation<F, Fut, T, E>(options: RetryOptions, mut operation: F) -> SafeResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, E>>,
+    T: Default,
+    E: std::fmt::Display,
+{
+    let start = Instant::now();
+    let mut attempt = 0;
+
+    loop {
+        attempt += 1;
+        match operation().await {
+            Ok(value) => {
+                return SafeResult::success(value).with_retry_info(None, attempt, start.elapsed());
+            }
+            Err(e) => {
+                let Some(transient) = classify_error(&e.to_string()) else {
+                    return SafeResult::<T>::error(T::default()).with_retry_info(
+                        None,
+                        attempt,
+                        start.elapsed(),
+                    );
+                };
+
+                if attempt >= options.max_attempts || start.elapsed() >= options.deadline {
+                    return SafeResult::<T>::navigation_error().with_retry_info(
+                        Some(transient),
+                        attempt,
+                        start.elapsed(),
+                    );
+                }
+
+                tokio::time::sleep(backoff_delay(&options, attempt - 1)).await;
+            }
         }
     }
 }
@@ -196,6 +547,18 @@ mod tests {
         assert!(!is_timeout_error("Navigation error"));
     }
 
+    #[test]
+    fn is_dialog_error_detects_unexpected_alert_open() {
+        assert!(is_dialog_error("unexpected alert open"));
+        assert!(is_dialog_error("Unexpected Alert Open: {Alert text : hi}"));
+    }
+
+    #[test]
+    fn is_dialog_error_false_for_other_errors() {
+        assert!(!is_dialog_error("Element not found"));
+        assert!(!is_dialog_error("Navigation error"));
+    }
+
     #[test]
     fn safe_result_success() {
         let result = SafeResult::success(42);
@@ -219,4 +582,157 @@ mod tests {
         assert!(!result.success);
         assert!(!result.navigation_error);
     }
+
+    #[test]
+    fn safe_result_with_events_attaches_buffer() {
+        let result = SafeResult::success(1).with_events(vec![PageActivityEvent::RequestStarted]);
+        assert_eq!(result.events, vec![PageActivityEvent::RequestStarted]);
+    }
+
+    #[test]
+    fn is_error_event_detects_navigation_exception() {
+        assert!(is_error_event(&PageActivityEvent::ExceptionThrown {
+            text: "Execution context was destroyed".to_string(),
+            stack: None,
+        }));
+        assert!(!is_error_event(&PageActivityEvent::ExceptionThrown {
+            text: "TypeError: x is not a function".to_string(),
+            stack: None,
+        }));
+    }
+
+    #[test]
+    fn is_error_event_detects_zero_status_response() {
+        assert!(is_error_event(&PageActivityEvent::ResponseReceived {
+            url: "https://example.com/aborted".to_string(),
+            status: 0,
+        }));
+        assert!(!is_error_event(&PageActivityEvent::ResponseReceived {
+            url: "https://example.com/ok".to_string(),
+            status: 200,
+        }));
+    }
+
+    #[test]
+    fn is_error_event_ignores_unrelated_events() {
+        assert!(!is_error_event(&PageActivityEvent::RequestStarted));
+        assert!(!is_error_event(&PageActivityEvent::ConsoleApiCalled {
+            level: "log".to_string(),
+            args: vec!["hello".to_string()],
+        }));
+    }
+
+    #[test]
+    fn classify_error_maps_to_specific_variants() {
+        assert!(matches!(
+            classify_error("Target was detached"),
+            Some(NavigationError::TargetDetached)
+        ));
+        assert!(matches!(
+            classify_error("Execution context was destroyed"),
+            Some(NavigationError::ExecutionContextDestroyed)
+        ));
+        assert!(matches!(
+            classify_error("Page navigated away"),
+            Some(NavigationError::PageNavigatedAway)
+        ));
+        assert!(matches!(
+            classify_error("Navigation timed out after 30000ms"),
+            Some(NavigationError::Timeout(30000))
+        ));
+        assert!(matches!(
+            classify_error("Navigation was interrupted: user clicked back"),
+            Some(NavigationError::Interrupted(_))
+        ));
+    }
+
+    #[test]
+    fn classify_error_none_for_unrecognized_message() {
+        assert!(classify_error("Element not found").is_none());
+    }
+
+    #[test]
+    fn register_error_pattern_extends_classification() {
+        register_error_pattern("stale element reference", |_| {
+            NavigationError::TargetDetached
+        });
+        assert!(matches!(
+            classify_error("StaleElementReferenceException: stale element reference"),
+            Some(NavigationError::TargetDetached)
+        ));
+    }
+
+    #[tokio::test]
+    async fn retry_operation_succeeds_on_first_attempt() {
+        let result = retry_operation(RetryOptions::default(), || async { Ok::<_, EngineError>(7) })
+            .await;
+
+        assert!(result.success);
+        assert_eq!(result.value, 7);
+        assert_eq!(result.attempts, 1);
+        assert!(result.final_error.is_none());
+    }
+
+    #[tokio::test]
+    async fn retry_operation_retries_transient_errors_then_succeeds() {
+        let calls = std::sync::atomic::AtomicU32::new(0);
+        let options = RetryOptions {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            deadline: Duration::from_secs(5),
+        };
+
+        let result = retry_operation(options, || {
+            let n = calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            async move {
+                if n < 2 {
+                    Err(EngineError::Browser("Target was detached".to_string()))
+                } else {
+                    Ok(42)
+                }
+            }
+        })
+        .await;
+
+        assert!(result.success);
+        assert_eq!(result.value, 42);
+        assert_eq!(result.attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn retry_operation_gives_up_after_max_attempts() {
+        let options = RetryOptions {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(2),
+            deadline: Duration::from_secs(5),
+        };
+
+        let result = retry_operation(options, || async {
+            Err::<i32, _>(EngineError::Browser("Target was detached".to_string()))
+        })
+        .await;
+
+        assert!(!result.success);
+        assert!(result.navigation_error);
+        assert_eq!(result.attempts, 3);
+        assert!(matches!(
+            result.final_error,
+            Some(NavigationError::TargetDetached)
+        ));
+    }
+
+    #[tokio::test]
+    async fn retry_operation_short_circuits_on_permanent_error() {
+        let result = retry_operation(RetryOptions::default(), || async {
+            Err::<i32, _>(EngineError::ElementNotFound("widget".to_string()))
+        })
+        .await;
+
+        assert!(!result.success);
+        assert!(!result.navigation_error);
+        assert_eq!(result.attempts, 1);
+        assert!(result.final_error.is_none());
+    }
 }
@@ -57,6 +57,37 @@ pub async fn get_attribute(
     adapter.get_attribute(selector, attribute).await
 }
 
+/// Get the serialized HTML of the whole document, for feeding into
+/// downstream HTML parsing (e.g. scraping or snapshotting).
+///
+/// # Arguments
+///
+/// * `adapter` - The engine adapter to use
+///
+/// # Returns
+///
+/// The document's outer HTML
+pub async fn page_source(adapter: &dyn EngineAdapter) -> Result<String, EngineError> {
+    adapter.page_source().await
+}
+
+/// Get the serialized HTML (`outerHTML`) of a single element.
+///
+/// # Arguments
+///
+/// * `adapter` - The engine adapter to use
+/// * `selector` - The CSS selector for the element
+///
+/// # Returns
+///
+/// The element's outer HTML, or `None` if not found
+pub async fn outer_html(
+    adapter: &dyn EngineAdapter,
+    selector: &str,
+) -> Result<Option<String>, EngineError> {
+    adapter.outer_html(selector).await
+}
+
 /// Check if an input element is empty.
 ///
 /// # Arguments
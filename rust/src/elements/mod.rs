@@ -10,11 +10,14 @@ pub mod selectors;
 pub mod visibility;
 
 pub use content::{
-    get_attribute, input_value, is_element_empty, text_content, truncate_for_preview,
-    ElementLogInfo,
+    get_attribute, input_value, is_element_empty, outer_html, page_source, text_content,
+    truncate_for_preview, ElementLogInfo,
 };
 pub use selectors::{
-    build_text_selector, escape_selector_value, extract_text_from_selector, has_nth_of_type,
-    is_text_selector, normalize_selector, parse_nth_of_type, ParsedSelector,
+    build_text_selector, escape_selector_value, extract_element_from_selector,
+    extract_text_from_selector, has_nth_of_type, is_text_selector, normalize_selector,
+    parse_nth_of_type, xpath_literal, ParsedSelector, TextMatchMode,
+};
+pub use visibility::{
+    count, is_clickable, is_enabled, is_in_viewport, is_occluded, is_visible, needs_scrolling,
 };
-pub use visibility::{count, is_enabled, is_in_viewport, is_visible, needs_scrolling};
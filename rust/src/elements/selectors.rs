@@ -6,14 +6,27 @@
 use regex::Regex;
 use std::sync::LazyLock;
 
-/// Pattern for detecting text-based selectors like `:text("Submit")`.
-static TEXT_SELECTOR_PATTERN: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r#"^:text\(["'](.+?)["']\)$"#).expect("Invalid regex pattern"));
+/// Pattern for detecting text-based selectors like `:text("Submit")` or
+/// `button:text("Submit")`.
+static TEXT_SELECTOR_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"^(?:([a-zA-Z][\w-]*):)?text\(["'](.+?)["']\)$"#).expect("Invalid regex pattern")
+});
 
 /// Pattern for detecting nth-of-type selectors.
 static NTH_OF_TYPE_PATTERN: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r#"(.+?):nth-of-type\((\d+)\)"#).expect("Invalid regex pattern"));
 
+/// How a text selector's content should be matched against an element's
+/// text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextMatchMode {
+    /// The element's text must equal the selector text exactly (no
+    /// whitespace normalization is applied).
+    Exact,
+    /// The element's text must contain the selector text anywhere.
+    Contains,
+}
+
 /// Represents a parsed selector.
 #[derive(Debug, Clone, PartialEq)]
 pub enum ParsedSelector {
@@ -23,6 +36,8 @@ pub enum ParsedSelector {
     Text {
         text: String,
         element: Option<String>,
+        match_mode: TextMatchMode,
+        case_insensitive: bool,
     },
     /// An XPath selector.
     XPath(String),
@@ -30,7 +45,8 @@ pub enum ParsedSelector {
 
 /// Check if a selector is a text-based selector.
 ///
-/// Text selectors have the format `:text("text content")`.
+/// Text selectors have the format `:text("text content")`, optionally
+/// prefixed with an element type (`button:text("Submit")`).
 ///
 /// # Arguments
 ///
@@ -55,7 +71,24 @@ pub fn is_text_selector(selector: &str) -> bool {
 pub fn extract_text_from_selector(selector: &str) -> Option<String> {
     TEXT_SELECTOR_PATTERN
         .captures(selector)
-        .map(|caps| caps[1].to_string())
+        .map(|caps| caps[2].to_string())
+}
+
+/// Extract the element prefix from a text selector, if present.
+///
+/// # Arguments
+///
+/// * `selector` - A text selector like `button:text("Submit")`
+///
+/// # Returns
+///
+/// The element type if the selector has one, `None` otherwise (including
+/// when `selector` is not a text selector at all)
+pub fn extract_element_from_selector(selector: &str) -> Option<String> {
+    TEXT_SELECTOR_PATTERN
+        .captures(selector)
+        .and_then(|caps| caps.get(1))
+        .map(|m| m.as_str().to_string())
 }
 
 /// Normalize a selector for consistent handling.
@@ -77,7 +110,9 @@ pub fn normalize_selector(selector: &str) -> ParsedSelector {
     if let Some(text) = extract_text_from_selector(trimmed) {
         return ParsedSelector::Text {
             text,
-            element: None,
+            element: extract_element_from_selector(trimmed),
+            match_mode: TextMatchMode::Contains,
+            case_insensitive: false,
         };
     }
 
@@ -90,25 +125,82 @@ pub fn normalize_selector(selector: &str) -> ParsedSelector {
     ParsedSelector::Css(trimmed.to_string())
 }
 
-/// Build a CSS selector to find elements by visible text.
+/// Build a safe XPath string literal for `text`.
+///
+/// XPath 1.0 has no string-escaping mechanism, so a literal must be quoted
+/// with whichever quote character doesn't appear in `text`. When `text`
+/// contains both `'` and `"`, it is split on `'` and reassembled with
+/// `concat(...)`, splicing in a literal `'` between pieces.
+///
+/// # Arguments
+///
+/// * `text` - The raw text to embed in an XPath expression
+///
+/// # Returns
+///
+/// An XPath literal expression (e.g. `'Submit'`, `"it's"`, or
+/// `concat('say "it', "'", 's" now')`) that evaluates to `text`.
+pub fn xpath_literal(text: &str) -> String {
+    if !text.contains('\'') {
+        return format!("'{text}'");
+    }
+    if !text.contains('"') {
+        return format!("\"{text}\"");
+    }
+
+    let mut parts: Vec<String> = Vec::new();
+    for (i, segment) in text.split('\'').enumerate() {
+        if i > 0 {
+            parts.push("\"'\"".to_string());
+        }
+        if !segment.is_empty() {
+            parts.push(format!("'{segment}'"));
+        }
+    }
+    if parts.is_empty() {
+        parts.push("''".to_string());
+    }
+    format!("concat({})", parts.join(", "))
+}
+
+/// Build an XPath selector to find elements by their text content.
 ///
-/// This creates a selector that matches elements containing the specified text.
 /// Note: This is a best-effort approach and may not work for all cases.
 ///
 /// # Arguments
 ///
 /// * `text` - The text to search for
 /// * `element_type` - Optional element type to restrict the search (e.g., "button")
+/// * `match_mode` - Whether `text` must match exactly or just be contained
+/// * `case_insensitive` - Whether to fold case before comparing
 ///
 /// # Returns
 ///
-/// A CSS selector string (or XPath for more complex cases)
-pub fn build_text_selector(text: &str, element_type: Option<&str>) -> String {
-    // For simple cases, use XPath as it has better text support
-    match element_type {
-        Some(el) => format!("//{}[contains(text(), '{}')]", el, text),
-        None => format!("//*[contains(text(), '{}')]", text),
-    }
+/// An XPath selector string
+pub fn build_text_selector(
+    text: &str,
+    element_type: Option<&str>,
+    match_mode: TextMatchMode,
+    case_insensitive: bool,
+) -> String {
+    let el = element_type.unwrap_or("*");
+
+    let (haystack, literal) = if case_insensitive {
+        (
+            "translate(text(), 'ABCDEFGHIJKLMNOPQRSTUVWXYZ', 'abcdefghijklmnopqrstuvwxyz')"
+                .to_string(),
+            xpath_literal(&text.to_lowercase()),
+        )
+    } else {
+        ("text()".to_string(), xpath_literal(text))
+    };
+
+    let predicate = match match_mode {
+        TextMatchMode::Contains => format!("contains({haystack}, {literal})"),
+        TextMatchMode::Exact => format!("{haystack}={literal}"),
+    };
+
+    format!("//{el}[{predicate}]")
 }
 
 /// Check if a selector contains an nth-of-type modifier.
@@ -168,6 +260,12 @@ mod tests {
         assert!(is_text_selector(":text(\"Click me\")"));
     }
 
+    #[test]
+    fn is_text_selector_true_with_element_prefix() {
+        assert!(is_text_selector("button:text(\"Submit\")"));
+        assert!(is_text_selector("a:text('Learn more')"));
+    }
+
     #[test]
     fn is_text_selector_false_for_css_selectors() {
         assert!(!is_text_selector("button"));
@@ -194,6 +292,24 @@ mod tests {
         assert_eq!(extract_text_from_selector(".class"), None);
     }
 
+    #[test]
+    fn extract_text_from_selector_extracts_text_with_element_prefix() {
+        assert_eq!(
+            extract_text_from_selector("button:text(\"Submit\")"),
+            Some("Submit".to_string())
+        );
+    }
+
+    #[test]
+    fn extract_element_from_selector_extracts_prefix() {
+        assert_eq!(
+            extract_element_from_selector("button:text(\"Submit\")"),
+            Some("button".to_string())
+        );
+        assert_eq!(extract_element_from_selector(":text(\"Submit\")"), None);
+        assert_eq!(extract_element_from_selector("button"), None);
+    }
+
     #[test]
     fn normalize_selector_handles_css() {
         assert_eq!(
@@ -212,7 +328,22 @@ mod tests {
             normalize_selector(":text(\"Submit\")"),
             ParsedSelector::Text {
                 text: "Submit".to_string(),
-                element: None
+                element: None,
+                match_mode: TextMatchMode::Contains,
+                case_insensitive: false,
+            }
+        );
+    }
+
+    #[test]
+    fn normalize_selector_handles_text_with_element_prefix() {
+        assert_eq!(
+            normalize_selector("button:text(\"Submit\")"),
+            ParsedSelector::Text {
+                text: "Submit".to_string(),
+                element: Some("button".to_string()),
+                match_mode: TextMatchMode::Contains,
+                case_insensitive: false,
             }
         );
     }
@@ -231,18 +362,61 @@ mod tests {
 
     #[test]
     fn build_text_selector_without_element() {
-        let selector = build_text_selector("Submit", None);
+        let selector = build_text_selector("Submit", None, TextMatchMode::Contains, false);
         assert!(selector.contains("Submit"));
         assert!(selector.contains("contains(text()"));
     }
 
     #[test]
     fn build_text_selector_with_element() {
-        let selector = build_text_selector("Submit", Some("button"));
+        let selector =
+            build_text_selector("Submit", Some("button"), TextMatchMode::Contains, false);
         assert!(selector.contains("button"));
         assert!(selector.contains("Submit"));
     }
 
+    #[test]
+    fn build_text_selector_escapes_apostrophe() {
+        let selector =
+            build_text_selector("It's here", None, TextMatchMode::Contains, false);
+        assert_eq!(selector, "//*[contains(text(), \"It's here\")]");
+    }
+
+    #[test]
+    fn build_text_selector_exact_match() {
+        let selector =
+            build_text_selector("Submit", Some("button"), TextMatchMode::Exact, false);
+        assert_eq!(selector, "//button[text()='Submit']");
+    }
+
+    #[test]
+    fn build_text_selector_case_insensitive() {
+        let selector = build_text_selector("Submit", None, TextMatchMode::Contains, true);
+        assert!(selector.contains("translate(text()"));
+        assert!(selector.contains("'submit'"));
+    }
+
+    #[test]
+    fn xpath_literal_uses_single_quotes_by_default() {
+        assert_eq!(xpath_literal("Submit"), "'Submit'");
+    }
+
+    #[test]
+    fn xpath_literal_uses_double_quotes_for_apostrophes() {
+        assert_eq!(xpath_literal("It's here"), "\"It's here\"");
+    }
+
+    #[test]
+    fn xpath_literal_uses_concat_for_mixed_quotes() {
+        let literal = xpath_literal("say \"it's\" now");
+        assert_eq!(literal, "concat('say \"it', \"'\", 's\" now')");
+    }
+
+    #[test]
+    fn xpath_literal_handles_text_of_only_apostrophes() {
+        assert_eq!(xpath_literal("'"), "\"'\"");
+    }
+
     #[test]
     fn has_nth_of_type_detects_pattern() {
         assert!(has_nth_of_type("button:nth-of-type(1)"));
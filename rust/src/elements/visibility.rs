@@ -3,7 +3,7 @@
 //! This module provides utilities for checking element visibility
 //! and viewport positioning.
 
-use crate::core::engine::{EngineAdapter, EngineError};
+use crate::core::engine::{EngineAdapter, EngineError, OcclusionInfo, ScrollAlignment, ViewportOffsets};
 
 /// Options for checking element visibility.
 #[derive(Debug, Clone)]
@@ -67,6 +67,46 @@ pub async fn count(adapter: &dyn EngineAdapter, selector: &str) -> Result<usize,
     adapter.count(selector).await
 }
 
+/// Point hit-test whether an element is occluded by another element (e.g.
+/// a modal overlay, cookie banner, or toast sitting on top of it), as
+/// opposed to merely being rendered somewhere in the DOM.
+///
+/// # Arguments
+///
+/// * `adapter` - The engine adapter to use
+/// * `selector` - The CSS selector for the element
+///
+/// # Returns
+///
+/// Structured hit-test info, including the occluding element's tag/class
+/// when the element is occluded
+pub async fn is_occluded(
+    adapter: &dyn EngineAdapter,
+    selector: &str,
+) -> Result<OcclusionInfo, EngineError> {
+    adapter.hit_test_occlusion(selector).await
+}
+
+/// Check whether an element can actually be clicked: it must be visible
+/// and not occluded by another element at its hit-test point.
+///
+/// # Arguments
+///
+/// * `adapter` - The engine adapter to use
+/// * `selector` - The CSS selector for the element
+///
+/// # Returns
+///
+/// `true` if the element is visible and unoccluded
+pub async fn is_clickable(adapter: &dyn EngineAdapter, selector: &str) -> Result<bool, EngineError> {
+    if !adapter.is_visible(selector).await? {
+        return Ok(false);
+    }
+
+    let occlusion = adapter.hit_test_occlusion(selector).await?;
+    Ok(!occlusion.occluded)
+}
+
 /// Calculate if an element is within the viewport.
 ///
 /// # Arguments
@@ -75,6 +115,9 @@ pub async fn count(adapter: &dyn EngineAdapter, selector: &str) -> Result<usize,
 /// * `viewport_width` - The viewport width
 /// * `viewport_height` - The viewport height
 /// * `margin` - Additional margin to consider element visible
+/// * `offsets` - Space occupied by sticky/fixed chrome that occludes the
+///   top and/or bottom of the viewport; the usable band shrinks to
+///   `[offsets.top, viewport_height - offsets.bottom]`
 ///
 /// # Returns
 ///
@@ -84,23 +127,31 @@ pub fn is_in_viewport(
     viewport_width: f64,
     viewport_height: f64,
     margin: f64,
+    offsets: ViewportOffsets,
 ) -> bool {
     let (x, y, width, height) = bounding_box;
 
-    // Check if element is at least partially visible with margin
-    let in_vertical = y < viewport_height - margin && (y + height) > margin;
+    // Check if element is at least partially visible with margin, within
+    // the band left usable after sticky/fixed chrome is excluded.
+    let in_vertical =
+        y < viewport_height - offsets.bottom - margin && (y + height) > offsets.top + margin;
     let in_horizontal = x < viewport_width - margin && (x + width) > margin;
 
     in_vertical && in_horizontal
 }
 
-/// Calculate if scrolling is needed to center an element.
+/// Calculate if scrolling is needed to satisfy the requested alignment.
 ///
 /// # Arguments
 ///
 /// * `bounding_box` - The element's bounding box (x, y, width, height)
 /// * `viewport_height` - The viewport height
 /// * `threshold_percent` - Percentage of viewport height to consider "significant"
+/// * `offsets` - Space occupied by sticky/fixed chrome that occludes the
+///   top and/or bottom of the viewport; the usable band shrinks to
+///   `[offsets.top, viewport_height - offsets.bottom]`, and alignment
+///   targets land within that band rather than the raw viewport
+/// * `alignment` - Where the element should come to rest once scrolled
 ///
 /// # Returns
 ///
@@ -109,19 +160,34 @@ pub fn needs_scrolling(
     bounding_box: (f64, f64, f64, f64),
     viewport_height: f64,
     threshold_percent: f64,
+    offsets: ViewportOffsets,
+    alignment: ScrollAlignment,
 ) -> bool {
     let (_, y, _, height) = bounding_box;
 
-    let element_center = y + height / 2.0;
-    let viewport_center = viewport_height / 2.0;
-    let distance_from_center = (element_center - viewport_center).abs();
-    let threshold_pixels = (viewport_height * threshold_percent) / 100.0;
+    let usable_top = offsets.top;
+    let usable_bottom = viewport_height - offsets.bottom;
+    let is_fully_visible = y >= usable_top && (y + height) <= usable_bottom;
+
+    // `Nearest` has no target resting position: it's satisfied as soon as
+    // the element is fully visible below the sticky chrome, regardless of
+    // where within the usable band that is.
+    if alignment == ScrollAlignment::Nearest {
+        return !is_fully_visible;
+    }
 
-    // Check if element is visible and within threshold
-    let is_visible = y >= 0.0 && (y + height) <= viewport_height;
-    let is_within_threshold = distance_from_center <= threshold_pixels;
+    let usable_height = usable_bottom - usable_top;
+    let target_y = match alignment {
+        ScrollAlignment::Start => usable_top,
+        ScrollAlignment::Center => usable_top + (usable_height - height) / 2.0,
+        ScrollAlignment::End => usable_bottom - height,
+        ScrollAlignment::Nearest => unreachable!("handled above"),
+    };
+    let distance_from_target = (y - target_y).abs();
+    let threshold_pixels = (viewport_height * threshold_percent) / 100.0;
+    let is_within_threshold = distance_from_target <= threshold_pixels;
 
-    !is_visible || !is_within_threshold
+    !is_fully_visible || !is_within_threshold
 }
 
 #[cfg(test)]
@@ -135,7 +201,8 @@ mod tests {
             (100.0, 100.0, 50.0, 50.0),
             800.0,
             600.0,
-            0.0
+            0.0,
+            ViewportOffsets::default()
         ));
     }
 
@@ -147,14 +214,16 @@ mod tests {
             (60.0, 100.0, 50.0, 50.0),
             800.0,
             600.0,
-            50.0
+            50.0,
+            ViewportOffsets::default()
         ));
         // Element partially visible at left edge (without margin requirement)
         assert!(is_in_viewport(
             (-10.0, 100.0, 50.0, 50.0),
             800.0,
             600.0,
-            0.0
+            0.0,
+            ViewportOffsets::default()
         ));
     }
 
@@ -165,14 +234,16 @@ mod tests {
             (100.0, -200.0, 50.0, 50.0),
             800.0,
             600.0,
-            50.0
+            50.0,
+            ViewportOffsets::default()
         ));
         // Element completely below viewport
         assert!(!is_in_viewport(
             (100.0, 700.0, 50.0, 50.0),
             800.0,
             600.0,
-            50.0
+            50.0,
+            ViewportOffsets::default()
         ));
     }
 
@@ -186,7 +257,9 @@ mod tests {
         assert!(!needs_scrolling(
             (0.0, element_y, 100.0, element_height),
             viewport_height,
-            10.0
+            10.0,
+            ViewportOffsets::default(),
+            ScrollAlignment::Center
         ));
     }
 
@@ -198,7 +271,9 @@ mod tests {
         assert!(needs_scrolling(
             (0.0, 10.0, 100.0, 50.0),
             viewport_height,
-            10.0
+            10.0,
+            ViewportOffsets::default(),
+            ScrollAlignment::Center
         ));
     }
 
@@ -210,7 +285,9 @@ mod tests {
         assert!(needs_scrolling(
             (0.0, 540.0, 100.0, 50.0),
             viewport_height,
-            10.0
+            10.0,
+            ViewportOffsets::default(),
+            ScrollAlignment::Center
         ));
     }
 
@@ -222,7 +299,128 @@ mod tests {
         assert!(needs_scrolling(
             (0.0, 700.0, 100.0, 50.0),
             viewport_height,
-            10.0
+            10.0,
+            ViewportOffsets::default(),
+            ScrollAlignment::Center
+        ));
+    }
+
+    #[test]
+    fn needs_scrolling_start_alignment() {
+        let viewport_height = 600.0;
+
+        // Already at the top - satisfies `Start`, even though it's nowhere
+        // near centered.
+        assert!(!needs_scrolling(
+            (0.0, 5.0, 100.0, 50.0),
+            viewport_height,
+            10.0,
+            ViewportOffsets::default(),
+            ScrollAlignment::Start
+        ));
+        // Centered, but `Start` wants it pinned to the top.
+        assert!(needs_scrolling(
+            (0.0, 270.0, 100.0, 60.0),
+            viewport_height,
+            10.0,
+            ViewportOffsets::default(),
+            ScrollAlignment::Start
+        ));
+    }
+
+    #[test]
+    fn needs_scrolling_end_alignment() {
+        let viewport_height = 600.0;
+
+        // Already flush with the bottom edge - satisfies `End`.
+        assert!(!needs_scrolling(
+            (0.0, 545.0, 100.0, 50.0),
+            viewport_height,
+            10.0,
+            ViewportOffsets::default(),
+            ScrollAlignment::End
+        ));
+        // At the top - far from the bottom-aligned target.
+        assert!(needs_scrolling(
+            (0.0, 0.0, 100.0, 50.0),
+            viewport_height,
+            10.0,
+            ViewportOffsets::default(),
+            ScrollAlignment::End
+        ));
+    }
+
+    #[test]
+    fn needs_scrolling_nearest_alignment() {
+        let viewport_height = 600.0;
+
+        // Fully visible anywhere in the viewport satisfies `Nearest`.
+        assert!(!needs_scrolling(
+            (0.0, 10.0, 100.0, 50.0),
+            viewport_height,
+            10.0,
+            ViewportOffsets::default(),
+            ScrollAlignment::Nearest
+        ));
+        // Only partially visible - still needs scrolling.
+        assert!(needs_scrolling(
+            (0.0, 580.0, 100.0, 50.0),
+            viewport_height,
+            10.0,
+            ViewportOffsets::default(),
+            ScrollAlignment::Nearest
+        ));
+    }
+
+    #[test]
+    fn needs_scrolling_behind_sticky_header() {
+        let viewport_height = 600.0;
+        let offsets = ViewportOffsets {
+            top: 80.0,
+            bottom: 0.0,
+        };
+
+        // The element sits at y=20, fully visible by the raw viewport but
+        // hidden underneath an 80px sticky header.
+        assert!(needs_scrolling(
+            (0.0, 20.0, 100.0, 50.0),
+            viewport_height,
+            10.0,
+            offsets,
+            ScrollAlignment::Nearest
+        ));
+        // Scrolled just below the header - no longer occluded.
+        assert!(!needs_scrolling(
+            (0.0, 85.0, 100.0, 50.0),
+            viewport_height,
+            10.0,
+            offsets,
+            ScrollAlignment::Nearest
+        ));
+    }
+
+    #[test]
+    fn is_in_viewport_excludes_sticky_footer() {
+        let offsets = ViewportOffsets {
+            top: 0.0,
+            bottom: 60.0,
+        };
+
+        // Element sits in the bottom 60px, which is reported as occluded.
+        assert!(!is_in_viewport(
+            (0.0, 560.0, 100.0, 30.0),
+            800.0,
+            600.0,
+            0.0,
+            offsets
+        ));
+        // Just above the footer - unobstructed.
+        assert!(is_in_viewport(
+            (0.0, 500.0, 100.0, 30.0),
+            800.0,
+            600.0,
+            0.0,
+            offsets
         ));
     }
 }
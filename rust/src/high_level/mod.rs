@@ -6,5 +6,6 @@
 pub mod universal_logic;
 
 pub use universal_logic::{
-    check_and_clear_flag, find_toggle_button, install_click_listener, wait_for_url_condition,
+    check_and_clear_flag, find_by_role, find_toggle_button, install_binding_click_listener,
+    install_click_listener, install_persistent_click_listener, wait_for_url_condition, ClickEvent,
 };
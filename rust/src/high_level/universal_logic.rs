@@ -2,10 +2,41 @@
 //!
 //! These are pure functions that work with any browser automation engine.
 
-use crate::core::engine::{EngineAdapter, EngineError};
+use crate::core::engine::{EngineAdapter, EngineError, ScriptHandle};
 use crate::core::navigation::is_navigation_error;
+use futures::stream::{Stream, StreamExt};
+use std::pin::Pin;
 use std::time::Duration;
 
+/// Build the click-detection script shared by [`install_click_listener`] and
+/// [`install_persistent_click_listener`].
+fn click_listener_script(button_text: &str, storage_key: &str) -> String {
+    format!(
+        r#"
+        (function() {{
+            document.addEventListener('click', (event) => {{
+                let element = event.target;
+                while (element && element !== document.body) {{
+                    const elementText = element.textContent?.trim() || '';
+                    if (elementText === '{}' ||
+                        ((element.tagName === 'A' || element.tagName === 'BUTTON') &&
+                         elementText.includes('{}'))) {{
+                        console.log('[Click Listener] Detected click on {} button!');
+                        window.sessionStorage.setItem('{}', 'true');
+                        break;
+                    }}
+                    element = element.parentElement;
+                }}
+            }}, true);
+        }})()
+        "#,
+        button_text.replace('\'', "\\'"),
+        button_text.replace('\'', "\\'"),
+        button_text.replace('\'', "\\'"),
+        storage_key.replace('\'', "\\'")
+    )
+}
+
 /// Wait for a URL condition to be met.
 ///
 /// # Arguments
@@ -76,18 +107,114 @@ pub async fn install_click_listener(
     button_text: &str,
     storage_key: &str,
 ) -> Result<bool, EngineError> {
+    let script = click_listener_script(button_text, storage_key);
+
+    match adapter.evaluate(&script).await {
+        Ok(_) => Ok(true),
+        Err(e) if is_navigation_error(&e.to_string()) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Install a click detection listener that survives navigation.
+///
+/// Unlike [`install_click_listener`], which injects the detection script
+/// into the current document only (and is wiped out by the very next
+/// navigation), this registers the script via
+/// [`EngineAdapter::add_script_on_new_document`] so it re-runs automatically
+/// at the start of every subsequent document load. This makes it suitable
+/// for multi-page flows where the target button may only appear after one
+/// or more navigations.
+///
+/// # Arguments
+///
+/// * `adapter` - The engine adapter to use
+/// * `button_text` - The button text to detect
+/// * `storage_key` - The session storage key to set
+///
+/// # Returns
+///
+/// A [`ScriptHandle`] that can be passed to
+/// [`EngineAdapter::remove_script_on_new_document`] to uninstall the
+/// listener.
+pub async fn install_persistent_click_listener(
+    adapter: &dyn EngineAdapter,
+    button_text: &str,
+    storage_key: &str,
+) -> Result<ScriptHandle, EngineError> {
+    let script = click_listener_script(button_text, storage_key);
+    adapter.add_script_on_new_document(&script).await
+}
+
+/// A structured click event delivered by [`install_binding_click_listener`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClickEvent {
+    /// The matched element's trimmed text content.
+    pub text: String,
+    /// A generated selector targeting the clicked element.
+    pub selector: String,
+}
+
+/// Build a CSS selector for `element`, used to identify the clicked element
+/// in a [`ClickEvent`]: `#id` when it has an id, otherwise its tag name with
+/// its `:nth-of-type` position among same-tag siblings. That second form is
+/// not qualified by ancestors, so it can also match elements elsewhere in
+/// the document.
+fn click_event_selector_script() -> &'static str {
+    r#"
+    (function(element) {
+        if (element.id) return '#' + element.id;
+        const parent = element.parentElement;
+        if (!parent) return element.tagName.toLowerCase();
+        const siblings = Array.from(parent.children).filter(
+            (el) => el.tagName === element.tagName
+        );
+        const index = siblings.indexOf(element) + 1;
+        return element.tagName.toLowerCase() + ':nth-of-type(' + index + ')';
+    })
+    "#
+}
+
+/// Install a push-based click detection listener backed by
+/// [`EngineAdapter::expose_binding`], as a structured alternative to
+/// [`install_click_listener`]'s sessionStorage-flag polling.
+///
+/// The listener script calls `window.<binding_name>(JSON.stringify({text,
+/// selector}))` on every matching click instead of setting a flag, and each
+/// call is delivered as a [`ClickEvent`] on the returned stream the instant
+/// it happens — no [`check_and_clear_flag`] polling interval to wait out.
+///
+/// # Arguments
+///
+/// * `adapter` - The engine adapter to use
+/// * `binding_name` - The name to expose the binding function under
+/// * `button_text` - The button text to detect
+///
+/// # Returns
+///
+/// A stream of [`ClickEvent`]s, one per matching click.
+pub async fn install_binding_click_listener(
+    adapter: &dyn EngineAdapter,
+    binding_name: &str,
+    button_text: &str,
+) -> Result<Pin<Box<dyn Stream<Item = ClickEvent> + Send>>, EngineError> {
+    let events = adapter.expose_binding(binding_name);
+
     let script = format!(
         r#"
         (function() {{
+            const describeElement = {selector_script};
             document.addEventListener('click', (event) => {{
                 let element = event.target;
                 while (element && element !== document.body) {{
                     const elementText = element.textContent?.trim() || '';
-                    if (elementText === '{}' ||
+                    if (elementText === '{button_text}' ||
                         ((element.tagName === 'A' || element.tagName === 'BUTTON') &&
-                         elementText.includes('{}'))) {{
-                        console.log('[Click Listener] Detected click on {} button!');
-                        window.sessionStorage.setItem('{}', 'true');
+                         elementText.includes('{button_text}'))) {{
+                        window.{binding_name}(JSON.stringify({{
+                            text: elementText,
+                            selector: describeElement(element),
+                        }}));
                         break;
                     }}
                     element = element.parentElement;
@@ -95,17 +222,20 @@ pub async fn install_click_listener(
             }}, true);
         }})()
         "#,
-        button_text.replace('\'', "\\'"),
-        button_text.replace('\'', "\\'"),
-        button_text.replace('\'', "\\'"),
-        storage_key.replace('\'', "\\'")
+        selector_script = click_event_selector_script(),
+        button_text = button_text.replace('\'', "\\'"),
+        binding_name = binding_name,
     );
+    adapter.add_script_on_new_document(&script).await?;
 
-    match adapter.evaluate(&script).await {
-        Ok(_) => Ok(true),
-        Err(e) if is_navigation_error(&e.to_string()) => Ok(false),
-        Err(e) => Err(e),
-    }
+    let events = events.filter_map(|event| async move {
+        let value: serde_json::Value = serde_json::from_str(&event.payload).ok()?;
+        Some(ClickEvent {
+            text: value.get("text")?.as_str()?.to_string(),
+            selector: value.get("selector")?.as_str()?.to_string(),
+        })
+    });
+    Ok(Box::pin(events))
 }
 
 /// Check and clear a session storage flag.
@@ -210,6 +340,123 @@ pub async fn find_toggle_button(
     Ok(None)
 }
 
+/// Find the first element matching an accessibility role and, optionally,
+/// an accessible-name substring.
+///
+/// Unlike [`find_toggle_button`], which relies on `data-qa` selectors and
+/// raw text/XPath matching, this locates elements the way an assistive
+/// technology would: by computing each candidate's accessible name
+/// following the ARIA naming algorithm (`aria-labelledby`, then
+/// `aria-label`, then an associated `<label>`/`alt`/`title`, else visible
+/// text) and its explicit (`role` attribute) or implicit (derived from tag
+/// name, e.g. `button`, `a[href]` -> `link`, `input[type=checkbox]` ->
+/// `checkbox`) role. This is robust against markup that uses icons instead
+/// of text, or `data-qa` attributes that vary release to release.
+///
+/// # Arguments
+///
+/// * `adapter` - The engine adapter to use
+/// * `role` - The ARIA role to match (e.g. `"button"`, `"link"`, `"checkbox"`)
+/// * `name_substring` - An optional accessible-name substring to require
+/// * `case_insensitive` - Fold case before comparing `name_substring`
+///
+/// # Returns
+///
+/// The generated selector for the first matching element, or `None` if no
+/// element matched both the role and the name substring.
+pub async fn find_by_role(
+    adapter: &dyn EngineAdapter,
+    role: &str,
+    name_substring: Option<&str>,
+    case_insensitive: bool,
+) -> Result<Option<String>, EngineError> {
+    let name_query_js = match name_substring {
+        Some(name) => format!("{name:?}"),
+        None => "null".to_string(),
+    };
+    let script = format!(
+        r#"return (function() {{
+            const role = {role:?};
+            const nameQuery = {name_query_js};
+            const caseInsensitive = {case_insensitive};
+            const fold = (s) => caseInsensitive ? s.toLowerCase() : s;
+            const target = nameQuery ? fold(nameQuery) : null;
+
+            const implicitRole = (el) => {{
+                const tag = el.tagName.toLowerCase();
+                switch (tag) {{
+                    case 'button': return 'button';
+                    case 'a': return el.hasAttribute('href') ? 'link' : null;
+                    case 'select': return el.multiple ? 'listbox' : 'combobox';
+                    case 'textarea': return 'textbox';
+                    case 'img': return 'img';
+                    case 'input': {{
+                        const type = (el.getAttribute('type') || 'text').toLowerCase();
+                        switch (type) {{
+                            case 'checkbox': return 'checkbox';
+                            case 'radio': return 'radio';
+                            case 'button':
+                            case 'submit':
+                            case 'reset':
+                                return 'button';
+                            case 'range': return 'slider';
+                            default: return 'textbox';
+                        }}
+                    }}
+                    default: return null;
+                }}
+            }};
+
+            const accessibleName = (el) => {{
+                const labelledBy = el.getAttribute('aria-labelledby');
+                if (labelledBy) {{
+                    const text = labelledBy.split(/\s+/)
+                        .map((id) => document.getElementById(id))
+                        .filter(Boolean)
+                        .map((node) => node.textContent.trim())
+                        .join(' ')
+                        .trim();
+                    if (text) return text;
+                }}
+                const label = el.getAttribute('aria-label');
+                if (label && label.trim()) return label.trim();
+                if (el.id) {{
+                    const labelEl = document.querySelector('label[for="' + el.id + '"]');
+                    if (labelEl && labelEl.textContent.trim()) return labelEl.textContent.trim();
+                }}
+                const closestLabel = el.closest('label');
+                if (closestLabel && closestLabel.textContent.trim()) {{
+                    return closestLabel.textContent.trim();
+                }}
+                if (el.hasAttribute('alt') && el.getAttribute('alt').trim()) {{
+                    return el.getAttribute('alt').trim();
+                }}
+                const title = el.getAttribute('title');
+                if (title && title.trim()) return title.trim();
+                return (el.textContent || '').trim();
+            }};
+
+            document
+                .querySelectorAll('[data-bc-role-match]')
+                .forEach((el) => el.removeAttribute('data-bc-role-match'));
+
+            for (const el of document.querySelectorAll('*')) {{
+                const elementRole = el.getAttribute('role') || implicitRole(el);
+                if (elementRole !== role) continue;
+                if (target && !fold(accessibleName(el)).includes(target)) continue;
+
+                el.setAttribute('data-bc-role-match', '1');
+                return '[data-bc-role-match="1"]';
+            }}
+
+            return null;
+        }})()"#
+    );
+
+    let value = adapter.evaluate(&script).await?;
+    Ok(value.as_str().map(str::to_string))
+}
+
 #[cfg(test)]
 mod tests {
     #[allow(unused_imports)]
@@ -4,7 +4,8 @@
 //! pre-click state capture and verification support.
 
 use crate::core::engine::{
-    ClickVerificationResult, EngineAdapter, EngineError, PreClickState,
+    ClickVerificationResult, EngineAdapter, EngineError, PreClickState, ScrollAlignment,
+    ViewportOffsets,
 };
 use crate::core::constants::TIMING;
 use crate::core::navigation::is_navigation_error;
@@ -18,6 +19,15 @@ pub struct ClickOptions {
     pub scroll_into_view: bool,
     /// Scroll behavior (smooth or instant).
     pub scroll_behavior: ScrollBehavior,
+    /// Where the element should come to rest in the viewport once scrolled.
+    pub scroll_alignment: ScrollAlignment,
+    /// Space occupied by sticky/fixed chrome that occludes part of the
+    /// viewport, passed through to the scroll-into-view step.
+    pub scroll_viewport_offsets: ViewportOffsets,
+    /// Whether to hit-test the element for occlusion (e.g. by a modal
+    /// overlay, cookie banner, or toast) right before clicking, and skip
+    /// the click with a clear reason if it's covered.
+    pub check_occlusion: bool,
     /// Wait time after scrolling.
     pub wait_after_scroll: Duration,
     /// Wait time after clicking.
@@ -33,6 +43,9 @@ impl Default for ClickOptions {
         Self {
             scroll_into_view: true,
             scroll_behavior: ScrollBehavior::Smooth,
+            scroll_alignment: ScrollAlignment::Center,
+            scroll_viewport_offsets: ViewportOffsets::default(),
+            check_occlusion: true,
             wait_after_scroll: TIMING.default_wait_after_scroll,
             wait_after_click: Duration::from_millis(1000),
             verify: true,
@@ -113,7 +126,14 @@ pub async fn capture_pre_click_state(
                 ariaSelected: el.getAttribute('aria-selected'),
                 checked: el.checked || false,
                 className: el.className || '',
-                isConnected: el.isConnected
+                isConnected: el.isConnected,
+                role: el.getAttribute('role') || el.localName,
+                ariaChecked: el.getAttribute('aria-checked'),
+                ariaDisabled: el.getAttribute('aria-disabled'),
+                ariaCurrent: el.getAttribute('aria-current'),
+                ariaInvalid: el.getAttribute('aria-invalid'),
+                value: (el.value !== undefined) ? String(el.value) : null,
+                textContent: el.textContent || ''
             }};
         }})()
         "#,
@@ -134,6 +154,13 @@ pub async fn capture_pre_click_state(
         checked: result.get("checked").and_then(|v| v.as_bool()),
         class_name: result.get("className").and_then(|v| v.as_str()).map(String::from),
         is_connected: result.get("isConnected").and_then(|v| v.as_bool()).unwrap_or(false),
+        role: result.get("role").and_then(|v| v.as_str()).map(String::from),
+        aria_checked: result.get("ariaChecked").and_then(|v| v.as_str()).map(String::from),
+        aria_disabled: result.get("ariaDisabled").and_then(|v| v.as_str()).map(String::from),
+        aria_current: result.get("ariaCurrent").and_then(|v| v.as_str()).map(String::from),
+        aria_invalid: result.get("ariaInvalid").and_then(|v| v.as_str()).map(String::from),
+        value: result.get("value").and_then(|v| v.as_str()).map(String::from),
+        text_content: result.get("textContent").and_then(|v| v.as_str()).map(String::from),
     })
 }
 
@@ -206,6 +233,54 @@ pub async fn verify_click(
         });
     }
 
+    if pre_click_state.aria_checked != post_click_state.aria_checked {
+        return Ok(ClickVerificationResult {
+            verified: true,
+            reason: "aria-checked changed".to_string(),
+            navigation_error: false,
+        });
+    }
+
+    if pre_click_state.aria_disabled != post_click_state.aria_disabled {
+        return Ok(ClickVerificationResult {
+            verified: true,
+            reason: "aria-disabled changed".to_string(),
+            navigation_error: false,
+        });
+    }
+
+    if pre_click_state.aria_current != post_click_state.aria_current {
+        return Ok(ClickVerificationResult {
+            verified: true,
+            reason: "aria-current changed".to_string(),
+            navigation_error: false,
+        });
+    }
+
+    if pre_click_state.aria_invalid != post_click_state.aria_invalid {
+        return Ok(ClickVerificationResult {
+            verified: true,
+            reason: "aria-invalid changed".to_string(),
+            navigation_error: false,
+        });
+    }
+
+    if pre_click_state.value != post_click_state.value {
+        return Ok(ClickVerificationResult {
+            verified: true,
+            reason: "value changed".to_string(),
+            navigation_error: false,
+        });
+    }
+
+    if pre_click_state.text_content != post_click_state.text_content {
+        return Ok(ClickVerificationResult {
+            verified: true,
+            reason: "textContent changed".to_string(),
+            navigation_error: false,
+        });
+    }
+
     // If element is still connected and not disabled, assume click worked
     if post_click_state.is_connected {
         return Ok(ClickVerificationResult {
@@ -295,6 +370,8 @@ pub async fn click_button(
     if options.scroll_into_view {
         let scroll_options = ScrollOptions {
             behavior: options.scroll_behavior,
+            alignment: options.scroll_alignment,
+            viewport_offsets: options.scroll_viewport_offsets,
             wait_after_scroll: options.wait_after_scroll,
             ..Default::default()
         };
@@ -308,6 +385,29 @@ pub async fn click_button(
         }
     }
 
+    // Gate on occlusion: a visible element can still be covered by a
+    // modal overlay, cookie banner, or toast, in which case a real click
+    // would land on that covering element instead of the target.
+    if options.check_occlusion {
+        let occlusion = adapter.hit_test_occlusion(selector).await?;
+        if occlusion.occluded {
+            let reason = match (&occlusion.occluding_tag, &occlusion.reason) {
+                (Some(tag), _) => format!(
+                    "element occluded by <{}{}>",
+                    tag,
+                    occlusion
+                        .occluding_class
+                        .as_deref()
+                        .map(|c| format!(" class=\"{c}\""))
+                        .unwrap_or_default()
+                ),
+                (None, Some(reason)) => format!("element occluded: {reason}"),
+                (None, None) => "element occluded".to_string(),
+            };
+            return Ok(ClickResult::failed(reason));
+        }
+    }
+
     // Perform the click
     let result = click_element(adapter, selector, options).await?;
 
@@ -328,6 +428,12 @@ mod tests {
         let options = ClickOptions::default();
         assert!(options.scroll_into_view);
         assert_eq!(options.scroll_behavior, ScrollBehavior::Smooth);
+        assert_eq!(options.scroll_alignment, ScrollAlignment::Center);
+        assert_eq!(
+            options.scroll_viewport_offsets,
+            crate::core::engine::ViewportOffsets::default()
+        );
+        assert!(options.check_occlusion);
         assert!(options.verify);
     }
 
@@ -4,11 +4,12 @@
 //! with verification support.
 
 use crate::core::constants::TIMING;
-use crate::core::engine::{EngineAdapter, EngineError, FillVerificationResult};
+use crate::core::engine::{EngineAdapter, EngineError, FillVerificationResult, PageActivityEvent};
 use crate::core::navigation::is_navigation_error;
 use crate::elements::content::is_element_empty;
 use crate::interactions::click::click_element;
 use crate::interactions::scroll::{scroll_into_view_if_needed, ScrollOptions};
+use futures::{FutureExt, StreamExt};
 use std::time::{Duration, Instant};
 
 /// Options for fill operations.
@@ -55,6 +56,10 @@ pub struct FillResult {
     pub skipped: bool,
     /// The actual value after filling.
     pub actual_value: Option<String>,
+    /// Every page activity event (console messages, exceptions, network
+    /// requests, ...) observed while the fill typed its text, even if the
+    /// fill itself succeeded.
+    pub events: Vec<PageActivityEvent>,
 }
 
 impl FillResult {
@@ -65,6 +70,7 @@ impl FillResult {
             verified: true,
             skipped: false,
             actual_value: Some(actual_value),
+            events: Vec::new(),
         }
     }
 
@@ -75,6 +81,7 @@ impl FillResult {
             verified: false,
             skipped: true,
             actual_value: Some(actual_value),
+            events: Vec::new(),
         }
     }
 
@@ -85,8 +92,15 @@ impl FillResult {
             verified: false,
             skipped: false,
             actual_value: None,
+            events: Vec::new(),
         }
     }
+
+    /// Attach captured page activity events to this result.
+    pub fn with_events(mut self, events: Vec<PageActivityEvent>) -> Self {
+        self.events = events;
+        self
+    }
 }
 
 /// Verify a fill operation by checking the element's value.
@@ -168,6 +182,8 @@ pub async fn perform_fill(
     text: &str,
     options: &FillOptions,
 ) -> Result<FillResult, EngineError> {
+    let mut activity = adapter.page_activity();
+
     // Perform the fill operation
     if options.simulate_typing {
         match adapter.type_text(selector, text).await {
@@ -187,6 +203,14 @@ pub async fn perform_fill(
         }
     }
 
+    // Drain every activity event the page produced while typing, without
+    // blocking - an adapter that doesn't implement the stream never
+    // has any ready.
+    let mut events = Vec::new();
+    while let Some(Some(event)) = activity.next().now_or_never() {
+        events.push(event);
+    }
+
     // Verify if requested
     if options.verify {
         let verification = verify_fill(adapter, selector, text, options).await?;
@@ -195,6 +219,7 @@ pub async fn perform_fill(
             verified: verification.verified,
             skipped: false,
             actual_value: Some(verification.actual_value),
+            events,
         })
     } else {
         Ok(FillResult {
@@ -202,6 +227,7 @@ pub async fn perform_fill(
             verified: true,
             skipped: false,
             actual_value: None,
+            events,
         })
     }
 }
@@ -291,6 +317,16 @@ mod tests {
         assert!(result.verified);
         assert!(!result.skipped);
         assert_eq!(result.actual_value, Some("filled text".to_string()));
+        assert!(result.events.is_empty());
+    }
+
+    #[test]
+    fn fill_result_with_events_attaches_buffer() {
+        let result = FillResult::failed().with_events(vec![PageActivityEvent::ExceptionThrown {
+            text: "boom".to_string(),
+            stack: None,
+        }]);
+        assert_eq!(result.events.len(), 1);
     }
 
     #[test]
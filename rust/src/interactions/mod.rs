@@ -8,12 +8,15 @@
 pub mod click;
 pub mod fill;
 pub mod scroll;
+pub mod scroll_to_text;
 
 pub use click::{
     capture_pre_click_state, click_button, click_element, verify_click, ClickOptions, ClickResult,
 };
 pub use fill::{fill_text_area, perform_fill, verify_fill, FillOptions, FillResult};
+pub use crate::core::engine::{ScrollAlignment, ScrollSnapInfo, TextMatchInfo, ViewportOffsets};
 pub use scroll::{
     scroll_into_view, scroll_into_view_if_needed, verify_scroll, ScrollBehavior, ScrollOptions,
     ScrollResult,
 };
+pub use scroll_to_text::{scroll_to_text, ScrollToTextOptions, ScrollToTextResult};
@@ -4,7 +4,10 @@
 //! with verification support.
 
 use crate::core::constants::TIMING;
-use crate::core::engine::{EngineAdapter, EngineError, ScrollVerificationResult};
+use crate::core::engine::{
+    EngineAdapter, EngineError, ScrollAlignment, ScrollSnapInfo, ScrollVerificationResult,
+    ViewportOffsets,
+};
 use std::time::{Duration, Instant};
 
 /// Scroll behavior options.
@@ -31,6 +34,12 @@ impl std::fmt::Display for ScrollBehavior {
 pub struct ScrollOptions {
     /// The scroll behavior (smooth or instant).
     pub behavior: ScrollBehavior,
+    /// Where the element should come to rest in the viewport once scrolled.
+    pub alignment: ScrollAlignment,
+    /// Space occupied by sticky/fixed chrome (e.g. a fixed navbar or
+    /// footer) that occludes part of the viewport; alignment targets land
+    /// within the band left over after this is excluded.
+    pub viewport_offsets: ViewportOffsets,
     /// Whether to verify the scroll operation.
     pub verify: bool,
     /// Timeout for verification.
@@ -47,6 +56,8 @@ impl Default for ScrollOptions {
     fn default() -> Self {
         Self {
             behavior: ScrollBehavior::Smooth,
+            alignment: ScrollAlignment::Center,
+            viewport_offsets: ViewportOffsets::default(),
             verify: true,
             verification_timeout: TIMING.verification_timeout,
             verification_retry_interval: TIMING.verification_retry_interval,
@@ -65,6 +76,11 @@ pub struct ScrollResult {
     pub verified: bool,
     /// Whether the scroll was skipped because element was already in view.
     pub skipped: bool,
+    /// Whether a CSS scroll-snap ancestor was detected for the element.
+    pub snap_detected: bool,
+    /// The corrected scroll offset the snap container was set to, when a
+    /// snap container was detected.
+    pub snap_offset: Option<f64>,
 }
 
 impl ScrollResult {
@@ -74,6 +90,8 @@ impl ScrollResult {
             scrolled: false,
             verified: true,
             skipped: true,
+            snap_detected: false,
+            snap_offset: None,
         }
     }
 
@@ -83,6 +101,8 @@ impl ScrollResult {
             scrolled: true,
             verified,
             skipped: false,
+            snap_detected: false,
+            snap_offset: None,
         }
     }
 
@@ -92,8 +112,17 @@ impl ScrollResult {
             scrolled: false,
             verified: false,
             skipped: false,
+            snap_detected: false,
+            snap_offset: None,
         }
     }
+
+    /// Attach scroll-snap correction info to this result.
+    pub fn with_snap(mut self, snap: ScrollSnapInfo) -> Self {
+        self.snap_detected = snap.detected;
+        self.snap_offset = snap.snap_offset;
+        self
+    }
 }
 
 /// Scroll an element into view.
@@ -112,13 +141,18 @@ pub async fn scroll_into_view(
     selector: &str,
     options: &ScrollOptions,
 ) -> Result<ScrollResult, EngineError> {
-    adapter.scroll_into_view(selector).await?;
+    adapter.scroll_into_view(selector, options.alignment).await?;
+
+    // Correct for any CSS scroll-snap ancestor: a raw centering offset can
+    // land the element somewhere the container immediately snaps away
+    // from on the next scroll event.
+    let snap = adapter.apply_scroll_snap(selector).await?;
 
     if options.verify {
         let verification = verify_scroll(adapter, selector, options).await?;
-        Ok(ScrollResult::performed(verification.verified))
+        Ok(ScrollResult::performed(verification.verified).with_snap(snap))
     } else {
-        Ok(ScrollResult::performed(true))
+        Ok(ScrollResult::performed(true).with_snap(snap))
     }
 }
 
@@ -140,19 +174,38 @@ pub async fn verify_scroll(
 ) -> Result<ScrollVerificationResult, EngineError> {
     let start_time = Instant::now();
     let mut attempts = 0u32;
+    let required_ratio = options.threshold_percent / 100.0;
+    let mut last_ratio = 0.0;
+    let mut last_stable = false;
 
     while start_time.elapsed() < options.verification_timeout {
         attempts += 1;
 
-        // Check if element is visible (indicating it's in viewport)
-        let is_visible = adapter.is_visible(selector).await?;
+        // `is_visible` only reports whether any part of the element is
+        // visible, so it can't honor a partial-visibility threshold; use
+        // the measured intersection ratio instead.
+        last_ratio = adapter.intersection_ratio(selector).await?;
 
-        if is_visible {
-            return Ok(ScrollVerificationResult {
-                verified: true,
-                in_viewport: true,
-                attempts,
-            });
+        if last_ratio >= required_ratio {
+            // Don't accept an in-flight smooth-scroll animation as already
+            // settled: require the bounding box to be unchanged across two
+            // consecutive animation frames before declaring victory.
+            last_stable = adapter
+                .is_bounding_box_stable(
+                    selector,
+                    options.verification_retry_interval.as_millis() as u64,
+                )
+                .await?;
+
+            if last_stable {
+                return Ok(ScrollVerificationResult {
+                    verified: true,
+                    in_viewport: true,
+                    attempts,
+                    visible_ratio: last_ratio,
+                    stable: true,
+                });
+            }
         }
 
         tokio::time::sleep(options.verification_retry_interval).await;
@@ -160,8 +213,10 @@ pub async fn verify_scroll(
 
     Ok(ScrollVerificationResult {
         verified: false,
-        in_viewport: false,
+        in_viewport: last_ratio >= required_ratio,
         attempts,
+        visible_ratio: last_ratio,
+        stable: last_stable,
     })
 }
 
@@ -207,6 +262,128 @@ pub async fn scroll_into_view_if_needed(
 mod tests {
     use super::*;
 
+    /// Minimal adapter with scriptable [`EngineAdapter::intersection_ratio`]
+    /// and [`EngineAdapter::is_bounding_box_stable`] results, used to
+    /// exercise [`verify_scroll`] without a real browser backend.
+    struct FakeRatioAdapter {
+        ratios: std::sync::Mutex<std::vec::IntoIter<f64>>,
+        stable: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl EngineAdapter for FakeRatioAdapter {
+        fn engine_type(&self) -> crate::core::engine::EngineType {
+            crate::core::engine::EngineType::Fantoccini
+        }
+
+        async fn url(&self) -> Result<String, EngineError> {
+            unimplemented!()
+        }
+
+        async fn goto(&self, _url: &str) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn query_selector(
+            &self,
+            _selector: &str,
+        ) -> Result<Option<crate::core::engine::ElementInfo>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn query_selector_all(
+            &self,
+            _selector: &str,
+        ) -> Result<Vec<crate::core::engine::ElementInfo>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn count(&self, _selector: &str) -> Result<usize, EngineError> {
+            unimplemented!()
+        }
+
+        async fn click(&self, _selector: &str) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn fill(&self, _selector: &str, _text: &str) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn type_text(&self, _selector: &str, _text: &str) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn text_content(&self, _selector: &str) -> Result<Option<String>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn input_value(&self, _selector: &str) -> Result<Option<String>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn get_attribute(
+            &self,
+            _selector: &str,
+            _attribute: &str,
+        ) -> Result<Option<String>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn is_visible(&self, _selector: &str) -> Result<bool, EngineError> {
+            unimplemented!()
+        }
+
+        async fn is_enabled(&self, _selector: &str) -> Result<bool, EngineError> {
+            unimplemented!()
+        }
+
+        async fn wait_for_selector(
+            &self,
+            _selector: &str,
+            _timeout_ms: u64,
+        ) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn scroll_into_view(
+            &self,
+            _selector: &str,
+            _alignment: crate::core::engine::ScrollAlignment,
+        ) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn evaluate(&self, _script: &str) -> Result<serde_json::Value, EngineError> {
+            unimplemented!()
+        }
+
+        async fn screenshot(&self) -> Result<Vec<u8>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn bring_to_front(&self) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn wait_for_navigation(&self, _timeout_ms: u64) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn intersection_ratio(&self, _selector: &str) -> Result<f64, EngineError> {
+            let mut ratios = self.ratios.lock().unwrap();
+            Ok(ratios.next().unwrap_or(0.0))
+        }
+
+        async fn is_bounding_box_stable(
+            &self,
+            _selector: &str,
+            _timeout_ms: u64,
+        ) -> Result<bool, EngineError> {
+            Ok(self.stable)
+        }
+    }
+
     #[test]
     fn scroll_behavior_display() {
         assert_eq!(ScrollBehavior::Smooth.to_string(), "smooth");
@@ -217,6 +394,8 @@ mod tests {
     fn scroll_options_default() {
         let options = ScrollOptions::default();
         assert_eq!(options.behavior, ScrollBehavior::Smooth);
+        assert_eq!(options.alignment, ScrollAlignment::Center);
+        assert_eq!(options.viewport_offsets, ViewportOffsets::default());
         assert!(options.verify);
         assert_eq!(options.threshold_percent, 10.0);
     }
@@ -227,6 +406,8 @@ mod tests {
         assert!(!result.scrolled);
         assert!(result.verified);
         assert!(result.skipped);
+        assert!(!result.snap_detected);
+        assert_eq!(result.snap_offset, None);
     }
 
     #[test]
@@ -249,4 +430,74 @@ mod tests {
         assert!(!result.verified);
         assert!(!result.skipped);
     }
+
+    #[test]
+    fn scroll_result_with_snap() {
+        let result = ScrollResult::performed(true).with_snap(ScrollSnapInfo {
+            detected: true,
+            snap_offset: Some(120.0),
+        });
+        assert!(result.snap_detected);
+        assert_eq!(result.snap_offset, Some(120.0));
+
+        let result = ScrollResult::performed(true).with_snap(ScrollSnapInfo::default());
+        assert!(!result.snap_detected);
+        assert_eq!(result.snap_offset, None);
+    }
+
+    #[tokio::test]
+    async fn verify_scroll_succeeds_once_ratio_meets_threshold_and_box_is_stable() {
+        let adapter = FakeRatioAdapter {
+            ratios: std::sync::Mutex::new(vec![0.8].into_iter()),
+            stable: true,
+        };
+        let options = ScrollOptions {
+            threshold_percent: 50.0,
+            ..Default::default()
+        };
+
+        let result = verify_scroll(&adapter, "#target", &options).await.unwrap();
+        assert!(result.verified);
+        assert!(result.in_viewport);
+        assert!(result.stable);
+        assert_eq!(result.visible_ratio, 0.8);
+    }
+
+    #[tokio::test]
+    async fn verify_scroll_fails_when_ratio_stays_below_threshold() {
+        let adapter = FakeRatioAdapter {
+            ratios: std::sync::Mutex::new(vec![0.1, 0.1, 0.1].into_iter()),
+            stable: true,
+        };
+        let options = ScrollOptions {
+            threshold_percent: 50.0,
+            verification_timeout: Duration::from_millis(50),
+            verification_retry_interval: Duration::from_millis(10),
+            ..Default::default()
+        };
+
+        let result = verify_scroll(&adapter, "#target", &options).await.unwrap();
+        assert!(!result.verified);
+        assert!(!result.in_viewport);
+        assert!(result.visible_ratio < 0.5);
+    }
+
+    #[tokio::test]
+    async fn verify_scroll_fails_while_bounding_box_is_still_settling() {
+        let adapter = FakeRatioAdapter {
+            ratios: std::sync::Mutex::new(vec![0.9; 10].into_iter()),
+            stable: false,
+        };
+        let options = ScrollOptions {
+            threshold_percent: 50.0,
+            verification_timeout: Duration::from_millis(50),
+            verification_retry_interval: Duration::from_millis(10),
+            ..Default::default()
+        };
+
+        let result = verify_scroll(&adapter, "#target", &options).await.unwrap();
+        assert!(!result.verified);
+        assert!(result.in_viewport, "ratio met the threshold even though the box never settled");
+        assert!(!result.stable);
+    }
 }
@@ -0,0 +1,131 @@
+//! Locating elements by their visible text content.
+//!
+//! This module provides a text-fragment style navigation primitive: find
+//! the first element containing a given text string, scroll to it, and
+//! optionally click it, without requiring a CSS selector.
+
+use crate::core::engine::{EngineAdapter, EngineError};
+use crate::interactions::click::{click_element, ClickOptions, ClickResult};
+use crate::interactions::scroll::{scroll_into_view_if_needed, ScrollOptions, ScrollResult};
+
+/// Options for locating an element by its visible text and optionally
+/// scrolling to and/or clicking it.
+#[derive(Debug, Clone, Default)]
+pub struct ScrollToTextOptions {
+    /// Fold case before comparing, mirroring the Text Fragment directive's
+    /// case-insensitive matching.
+    pub case_insensitive: bool,
+    /// Require the match to fall on a word boundary rather than matching
+    /// inside a larger word.
+    pub whole_word: bool,
+    /// Required text immediately preceding the match, disambiguating
+    /// between repeated occurrences — the `prefix-,` context of a
+    /// `#:~:text=prefix-,start,end,-suffix` directive.
+    pub prefix: Option<String>,
+    /// Required text immediately following the match, disambiguating
+    /// between repeated occurrences — the `,-suffix` context of a
+    /// `#:~:text=prefix-,start,end,-suffix` directive.
+    pub suffix: Option<String>,
+    /// Wrap the matched text in a `<mark>` element once found.
+    pub highlight: bool,
+    /// Click the matched element after scrolling to it.
+    pub click: bool,
+    /// Options controlling how the element is scrolled into view.
+    pub scroll_options: ScrollOptions,
+    /// Options controlling the click, when `click` is set.
+    pub click_options: ClickOptions,
+}
+
+/// Result of locating (and optionally scrolling to/clicking) an element by
+/// its visible text content.
+#[derive(Debug, Clone)]
+pub struct ScrollToTextResult {
+    /// Whether any element containing the text was found.
+    pub found: bool,
+    /// Total number of matching text nodes found in the document.
+    pub match_count: usize,
+    /// The generated selector for the chosen (first) match, if found.
+    pub selector: Option<String>,
+    /// The matched element's bounding box (x, y, width, height), if found.
+    pub bounding_box: Option<(f64, f64, f64, f64)>,
+    /// The result of scrolling to the match, if found.
+    pub scroll: Option<ScrollResult>,
+    /// The result of clicking the match, if `click` was requested and a
+    /// match was found.
+    pub click: Option<ClickResult>,
+}
+
+/// Locate the first element containing `text`, scroll it into view, and
+/// optionally click it — without requiring a CSS selector.
+///
+/// # Arguments
+///
+/// * `adapter` - The engine adapter to use
+/// * `text` - The visible text to search for
+/// * `options` - Matching, scroll, and click options
+///
+/// # Returns
+///
+/// A [`ScrollToTextResult`] reporting whether the text was found, how many
+/// matches existed, and the outcome of the scroll/click.
+pub async fn scroll_to_text(
+    adapter: &dyn EngineAdapter,
+    text: &str,
+    options: &ScrollToTextOptions,
+) -> Result<ScrollToTextResult, EngineError> {
+    let info = adapter
+        .find_by_text(
+            text,
+            options.case_insensitive,
+            options.whole_word,
+            options.prefix.as_deref(),
+            options.suffix.as_deref(),
+            options.highlight,
+        )
+        .await?;
+
+    if !info.found {
+        return Ok(ScrollToTextResult {
+            found: false,
+            match_count: info.match_count,
+            selector: None,
+            bounding_box: None,
+            scroll: None,
+            click: None,
+        });
+    }
+
+    let selector = info.selector.clone().ok_or_else(|| {
+        EngineError::Evaluation("text match reported found without a selector".to_string())
+    })?;
+
+    let scroll = scroll_into_view_if_needed(adapter, &selector, &options.scroll_options).await?;
+
+    let click = if options.click {
+        Some(click_element(adapter, &selector, &options.click_options).await?)
+    } else {
+        None
+    };
+
+    Ok(ScrollToTextResult {
+        found: true,
+        match_count: info.match_count,
+        selector: Some(selector),
+        bounding_box: info.bounding_box,
+        scroll: Some(scroll),
+        click,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scroll_to_text_options_default() {
+        let options = ScrollToTextOptions::default();
+        assert!(!options.case_insensitive);
+        assert!(!options.whole_word);
+        assert!(!options.click);
+    }
+}
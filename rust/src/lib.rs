@@ -37,19 +37,24 @@
 //! - [`browser`] - Browser management (launcher, navigation)
 //! - [`utilities`] - General utilities (URL handling, wait operations)
 //! - [`high_level`] - High-level DRY utilities
+//! - [`automation`] - Declarative, step-based automation
+//! - [`reporting`] - JUnit XML / NDJSON run reporting
 
+pub mod automation;
 pub mod browser;
 pub mod core;
 pub mod elements;
 pub mod high_level;
 pub mod interactions;
+pub mod reporting;
 pub mod utilities;
 
 // Re-export commonly used items at crate root
 pub use browser::{launch_browser, LaunchOptions, Browser, LaunchResult};
 pub use core::{
-    EngineAdapter, EngineError, EngineType, Logger, LoggerOptions, Timing,
-    CHROME_ARGS, TIMING,
+    BindingEvent, ContextId, EngineAdapter, EngineError, EngineType, LogFormat, Logger,
+    LoggerOptions, OcclusionInfo, ScriptHandle, ScrollAlignment, ScrollSnapInfo, TextMatchInfo,
+    TimeoutAdapter, Timing, ViewportOffsets, CHROME_ARGS, TIMING,
 };
 
 /// Prelude module for convenient imports.
@@ -59,30 +64,44 @@ pub use core::{
 /// use browser_commander::prelude::*;
 /// ```
 pub mod prelude {
+    pub use crate::automation::{
+        run_script, run_steps, Assertion, Feedback, RunnerEvent, ScriptStep, Step, StepOutcome,
+        TestAdapter,
+    };
     pub use crate::browser::{
-        goto, launch_browser, verify_navigation, wait_for_navigation,
+        apply_window_options, goto, launch_browser, verify_navigation, wait_for_navigation,
         wait_for_url_stabilization, Browser, LaunchOptions, LaunchResult,
         NavigationOptions, NavigationResult, WaitUntil,
     };
     pub use crate::core::{
-        is_navigation_error, is_timeout_error, EngineAdapter, EngineError, EngineType,
-        Logger, LoggerOptions, Timing, CHROME_ARGS, TIMING,
+        classify_error, is_navigation_error, is_timeout_error, register_error_pattern,
+        retry_operation, BindingEvent, ContextId, EngineAdapter, EngineError, EngineType,
+        LogFormat, Logger, LoggerOptions, NavigationError, OcclusionInfo, RetryOptions,
+        SafeResult, ScriptHandle, ScrollAlignment, ScrollSnapInfo, TextMatchInfo, TimeoutAdapter,
+        Timing, ViewportOffsets, CHROME_ARGS, TIMING,
     };
     pub use crate::elements::{
-        count, get_attribute, input_value, is_enabled, is_visible, normalize_selector,
-        text_content, ParsedSelector,
+        count, get_attribute, input_value, is_clickable, is_enabled, is_occluded, is_visible,
+        normalize_selector, outer_html, page_source, text_content, ParsedSelector, TextMatchMode,
     };
     pub use crate::high_level::{
-        check_and_clear_flag, find_toggle_button, install_click_listener,
-        wait_for_url_condition,
+        check_and_clear_flag, find_by_role, find_toggle_button, install_binding_click_listener,
+        install_click_listener, install_persistent_click_listener, wait_for_url_condition,
+        ClickEvent,
     };
     pub use crate::interactions::{
         click_button, click_element, fill_text_area, perform_fill, scroll_into_view,
-        scroll_into_view_if_needed, ClickOptions, ClickResult, FillOptions, FillResult,
-        ScrollBehavior, ScrollOptions, ScrollResult,
+        scroll_into_view_if_needed, scroll_to_text, ClickOptions, ClickResult, FillOptions,
+        FillResult, ScrollBehavior, ScrollOptions, ScrollResult,
+        ScrollToTextOptions, ScrollToTextResult,
+    };
+    pub use crate::reporting::{
+        to_junit_xml, to_ndjson, CaseStatus, RunRecorder, TestCase, TestSuite,
     };
     pub use crate::utilities::{
-        evaluate, get_domain, get_url, parse_url, safe_evaluate, same_origin,
-        unfocus_address_bar, wait, wait_with_cancel, WaitResult,
+        evaluate, evaluate_async, get_domain, get_url, parse_url, registrable_domain,
+        safe_evaluate, same_origin, same_site, schemeful_same_site, set_psl_source,
+        unfocus_address_bar, wait, wait_for_function, wait_with_cancel, PslError,
+        WaitForFunctionOptions, WaitForFunctionResult, WaitResult,
     };
 }
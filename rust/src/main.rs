@@ -2,24 +2,41 @@
 //!
 //! A command-line interface for the browser-commander library.
 
-use browser_commander::browser::{launch_browser, LaunchOptions};
-use browser_commander::core::logger::{init_logger, LoggerOptions};
+use browser_commander::automation::{resolve_script_path, WatchRunner};
+use browser_commander::browser::{launch_browser, LaunchOptions, WebDriverAdapter};
+use browser_commander::core::logger::{init_logger, LogFormat, LoggerOptions};
 use std::env;
+use std::path::Path;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    // Capture the initial working directory before anything in this process
+    // (or a step a `watch` run executes) has a chance to chdir, so relative
+    // script paths always resolve the same way.
+    let initial_cwd = env::current_dir()?;
+
     // Check for verbose flag
     let verbose = env::args().any(|arg| arg == "--verbose" || arg == "-v");
 
+    // Parse command-line arguments
+    let args: Vec<String> = env::args().collect();
+
+    let format = match flag_value(&args, "--log-format") {
+        Some("json") => LogFormat::Json,
+        _ => LogFormat::Human,
+    };
+    let directive = flag_value(&args, "--log-directive").map(str::to_string);
+
     // Initialize logging
-    init_logger(LoggerOptions { verbose });
+    init_logger(LoggerOptions {
+        verbose,
+        format,
+        directive,
+    });
 
     println!("Browser Commander v{}", env!("CARGO_PKG_VERSION"));
     println!();
 
-    // Parse command-line arguments
-    let args: Vec<String> = env::args().collect();
-
     if args.len() < 2 || args[1] == "--help" || args[1] == "-h" {
         print_help();
         return Ok(());
@@ -28,15 +45,70 @@ async fn main() -> anyhow::Result<()> {
     match args[1].as_str() {
         "launch" => {
             let headless = args.iter().any(|a| a == "--headless");
+            let engine = flag_value(&args, "--engine").unwrap_or("chromiumoxide");
+            let remote = flag_value(&args, "--remote");
+            let browser_name = flag_value(&args, "--browser-name");
 
-            let options = LaunchOptions::chromiumoxide()
-                .headless(headless)
-                .verbose(verbose);
+            let mut options = match engine {
+                "webdriver" => LaunchOptions::webdriver(
+                    remote.unwrap_or("http://localhost:4444"),
+                ),
+                _ => LaunchOptions::chromiumoxide(),
+            }
+            .headless(headless)
+            .verbose(verbose);
+            if let Some(name) = browser_name {
+                options = options.browser_name(name);
+            }
 
             println!("Launching browser...");
             let result = launch_browser(options).await?;
             println!("Browser launched: {:?}", result.browser);
         }
+        "watch" => {
+            let Some(script) = args.get(2) else {
+                eprintln!("Usage: browser-commander watch <script.json> [--url <start-url>] [--remote <url>] [--browser-name <name>] [--watch-also <path>]...");
+                return Ok(());
+            };
+            let script_path = resolve_script_path(&initial_cwd, Path::new(script));
+            let start_url = flag_value(&args, "--url").unwrap_or("about:blank");
+            let remote = flag_value(&args, "--remote").unwrap_or("http://localhost:4444");
+            let browser_name = flag_value(&args, "--browser-name");
+            let extra_paths: Vec<_> = flag_values(&args, "--watch-also")
+                .map(|p| resolve_script_path(&initial_cwd, Path::new(p)))
+                .collect();
+
+            let mut adapter = WebDriverAdapter::new(remote.parse()?);
+            if let Some(name) = browser_name {
+                adapter = adapter.with_browser_name(name);
+            }
+            let runner = WatchRunner::new(Box::new(adapter), script_path, extra_paths, start_url);
+            println!(
+                "Watching {} (Ctrl+C to stop)...",
+                runner
+                    .watched_paths()
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            runner
+                .watch(|cycle| {
+                    if !cycle.ran {
+                        println!("[watch] skipped: no relevant changes");
+                        return;
+                    }
+                    if let Some(suite) = cycle.suite {
+                        println!(
+                            "[watch] {} passed, {} failed, {} skipped",
+                            suite.passed(),
+                            suite.failed(),
+                            suite.skipped()
+                        );
+                    }
+                })
+                .await?;
+        }
         "version" => {
             println!("browser-commander {}", env!("CARGO_PKG_VERSION"));
         }
@@ -54,10 +126,35 @@ fn print_help() {
     println!();
     println!("Commands:");
     println!("  launch     Launch a browser instance");
+    println!("  watch      Run a JSON step script, re-running it on file changes");
     println!("  version    Show version information");
     println!();
     println!("Options:");
-    println!("  --headless     Run browser in headless mode");
-    println!("  --verbose, -v  Enable verbose logging");
-    println!("  --help, -h     Show this help message");
+    println!("  --headless          Run browser in headless mode");
+    println!("  --engine <name>     Engine to use: chromiumoxide (default) or webdriver");
+    println!("  --remote <url>      Remote WebDriver server URL (with --engine webdriver or watch)");
+    println!("  --url <url>         Start URL to reset to before each watch run");
+    println!("  --watch-also <path> Additional config/selector file to watch (repeatable)");
+    println!("  --verbose, -v       Enable verbose logging");
+    println!("  --log-format <fmt>  Diagnostic output format: human (default) or json");
+    println!("  --log-directive <d> Extra EnvFilter directive, e.g. browser_commander::interactions=debug");
+    println!("  --help, -h          Show this help message");
+}
+
+/// Get the value following a `--flag value` pair in the argument list.
+fn flag_value<'a>(args: &'a [String], flag: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))
+        .map(String::as_str)
+}
+
+/// Get every value following a repeated `--flag value` pair in the argument
+/// list, e.g. multiple `--watch-also <path>` occurrences.
+fn flag_values<'a>(args: &'a [String], flag: &str) -> impl Iterator<Item = &'a str> {
+    args.iter()
+        .enumerate()
+        .filter(move |(_, a)| *a == flag)
+        .filter_map(|(i, _)| args.get(i + 1))
+        .map(String::as_str)
 }
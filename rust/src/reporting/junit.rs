@@ -0,0 +1,139 @@
+//! JUnit XML serialization for a recorded run.
+//!
+//! Produces the conventional `<testsuites>`/`<testsuite>`/`<testcase>`
+//! hierarchy that CI systems (GitHub Actions, Jenkins, GitLab) already know
+//! how to ingest for pass/fail/skip reporting.
+
+use super::{CaseStatus, RunRecorder, TestCase, TestSuite};
+
+/// Serialize a recorded run as a JUnit XML document.
+pub fn to_junit_xml(recorder: &RunRecorder) -> String {
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+    let total_tests: usize = recorder.suites().iter().map(|s| s.cases.len()).sum();
+    let total_failures: usize = recorder.suites().iter().map(TestSuite::failed).sum();
+    let total_skipped: usize = recorder.suites().iter().map(TestSuite::skipped).sum();
+
+    xml.push_str(&format!(
+        "<testsuites tests=\"{total_tests}\" failures=\"{total_failures}\" skipped=\"{total_skipped}\">\n"
+    ));
+
+    for suite in recorder.suites() {
+        write_suite(&mut xml, suite);
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn write_suite(xml: &mut String, suite: &TestSuite) {
+    xml.push_str(&format!(
+        "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\">\n",
+        escape(&suite.name),
+        suite.cases.len(),
+        suite.failed(),
+        suite.skipped(),
+        suite.total_duration().as_secs_f64(),
+    ));
+
+    for case in &suite.cases {
+        write_case(xml, case);
+    }
+
+    xml.push_str("  </testsuite>\n");
+}
+
+fn write_case(xml: &mut String, case: &TestCase) {
+    xml.push_str(&format!(
+        "    <testcase name=\"{}\" time=\"{:.3}\">\n",
+        escape(&case.name),
+        case.duration.as_secs_f64(),
+    ));
+
+    xml.push_str("      <properties>\n");
+    if let Some(selector) = &case.selector {
+        xml.push_str(&format!(
+            "        <property name=\"selector\" value=\"{}\"/>\n",
+            escape(selector)
+        ));
+    }
+    if let Some(attempts) = case.attempts {
+        xml.push_str(&format!(
+            "        <property name=\"attempts\" value=\"{attempts}\"/>\n"
+        ));
+    }
+    xml.push_str("      </properties>\n");
+
+    match &case.status {
+        CaseStatus::Passed => {}
+        CaseStatus::Failed(message) => {
+            xml.push_str(&format!(
+                "      <failure message=\"{}\"/>\n",
+                escape(message)
+            ));
+        }
+        CaseStatus::Skipped(reason) => {
+            xml.push_str(&format!(
+                "      <skipped message=\"{}\"/>\n",
+                escape(reason)
+            ));
+        }
+    }
+
+    xml.push_str("    </testcase>\n");
+}
+
+/// Escape the characters JUnit XML attribute/text values can't contain raw.
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn to_junit_xml_includes_suite_and_case() {
+        let mut recorder = RunRecorder::new();
+        recorder.begin_suite("login");
+        recorder.record(TestCase {
+            name: "fill #email".to_string(),
+            selector: Some("#email".to_string()),
+            status: CaseStatus::Passed,
+            duration: Duration::from_millis(250),
+            attempts: Some(1),
+        });
+
+        let xml = to_junit_xml(&recorder);
+        assert!(xml.contains("<testsuites tests=\"1\" failures=\"0\" skipped=\"0\">"));
+        assert!(xml.contains("<testsuite name=\"login\""));
+        assert!(xml.contains("<testcase name=\"fill #email\""));
+        assert!(xml.contains("property name=\"attempts\" value=\"1\""));
+    }
+
+    #[test]
+    fn to_junit_xml_emits_failure_element() {
+        let mut recorder = RunRecorder::new();
+        recorder.begin_suite("checkout");
+        recorder.record(TestCase {
+            name: "click #submit".to_string(),
+            selector: Some("#submit".to_string()),
+            status: CaseStatus::Failed("element not found".to_string()),
+            duration: Duration::from_millis(10),
+            attempts: None,
+        });
+
+        let xml = to_junit_xml(&recorder);
+        assert!(xml.contains("<failure message=\"element not found\"/>"));
+    }
+
+    #[test]
+    fn escape_handles_xml_special_characters() {
+        assert_eq!(escape("<a> & \"b\""), "&lt;a&gt; &amp; &quot;b&quot;");
+    }
+}
@@ -0,0 +1,334 @@
+//! Structured run reporting for high-level automation operations.
+//!
+//! [`RunRecorder`] accumulates the [`TestCase`]s a caller records for
+//! high-level operations (a fill, a click, a navigation wait), grouped into
+//! [`TestSuite`]s (one per flow/page). The
+//! accumulated run can then be serialized as JUnit XML (for CI ingestion,
+//! see [`junit`]) or newline-delimited JSON (for streaming consumption, see
+//! [`ndjson`]).
+
+pub mod junit;
+pub mod ndjson;
+
+use crate::browser::navigation_ops::NavigationResult;
+use crate::interactions::click::ClickResult;
+use crate::interactions::fill::FillResult;
+use std::time::Duration;
+
+pub use junit::to_junit_xml;
+pub use ndjson::to_ndjson;
+
+/// The outcome of a single recorded operation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CaseStatus {
+    /// The operation completed successfully.
+    Passed,
+    /// The operation failed, with a diagnostic message.
+    Failed(String),
+    /// The operation was skipped, with a reason.
+    Skipped(String),
+}
+
+/// One recorded high-level operation.
+#[derive(Debug, Clone)]
+pub struct TestCase {
+    /// A short name for the operation, e.g. `"fill #email"`.
+    pub name: String,
+    /// The selector the operation targeted, if any.
+    pub selector: Option<String>,
+    /// The outcome of the operation.
+    pub status: CaseStatus,
+    /// Wall-clock time the operation took.
+    pub duration: Duration,
+    /// Verification attempt count, for operations that poll to verify
+    /// (e.g. [`crate::core::engine::FillVerificationResult::attempts`]).
+    pub attempts: Option<u32>,
+}
+
+impl TestCase {
+    /// Build a test case from a [`FillResult`].
+    pub fn from_fill_result(
+        selector: &str,
+        result: &FillResult,
+        duration: Duration,
+        attempts: Option<u32>,
+    ) -> Self {
+        let status = if result.skipped {
+            CaseStatus::Skipped("element already had content".to_string())
+        } else if result.filled && result.verified {
+            CaseStatus::Passed
+        } else {
+            CaseStatus::Failed(format!(
+                "fill not verified (actual_value: {:?})",
+                result.actual_value
+            ))
+        };
+
+        Self {
+            name: format!("fill {selector}"),
+            selector: Some(selector.to_string()),
+            status,
+            duration,
+            attempts,
+        }
+    }
+
+    /// Build a test case from a [`ClickResult`].
+    pub fn from_click_result(selector: &str, result: &ClickResult, duration: Duration) -> Self {
+        let status = if result.clicked && result.verified {
+            CaseStatus::Passed
+        } else if result.navigated {
+            CaseStatus::Passed
+        } else {
+            CaseStatus::Failed(result.reason.clone())
+        };
+
+        Self {
+            name: format!("click {selector}"),
+            selector: Some(selector.to_string()),
+            status,
+            duration,
+            attempts: None,
+        }
+    }
+
+    /// Build a test case from a declarative step's name and
+    /// [`StepOutcome`](crate::automation::StepOutcome), as produced by
+    /// [`crate::automation::run_steps`].
+    pub fn from_step_outcome(
+        name: &str,
+        outcome: &crate::automation::StepOutcome,
+        duration: Duration,
+    ) -> Self {
+        let status = match outcome {
+            crate::automation::StepOutcome::Ok => CaseStatus::Passed,
+            crate::automation::StepOutcome::Skipped => {
+                CaseStatus::Skipped("step skipped".to_string())
+            }
+            crate::automation::StepOutcome::Failed(reason) => {
+                CaseStatus::Failed(reason.clone())
+            }
+        };
+
+        Self {
+            name: name.to_string(),
+            selector: None,
+            status,
+            duration,
+            attempts: None,
+        }
+    }
+
+    /// Build a test case from a [`NavigationResult`].
+    pub fn from_navigation_result(
+        url: &str,
+        result: &NavigationResult,
+        duration: Duration,
+    ) -> Self {
+        let status = if result.verified {
+            CaseStatus::Passed
+        } else {
+            CaseStatus::Failed(
+                result
+                    .reason
+                    .clone()
+                    .unwrap_or_else(|| "navigation not verified".to_string()),
+            )
+        };
+
+        Self {
+            name: format!("goto {url}"),
+            selector: None,
+            status,
+            duration,
+            attempts: None,
+        }
+    }
+}
+
+/// A group of [`TestCase`]s for one flow or page, mapping to a JUnit
+/// `<testsuite>`.
+#[derive(Debug, Clone, Default)]
+pub struct TestSuite {
+    /// The suite's name, e.g. the flow or page under test.
+    pub name: String,
+    /// The recorded cases, in the order they ran.
+    pub cases: Vec<TestCase>,
+}
+
+impl TestSuite {
+    /// Create an empty suite with the given name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            cases: Vec::new(),
+        }
+    }
+
+    /// Number of passed cases.
+    pub fn passed(&self) -> usize {
+        self.cases
+            .iter()
+            .filter(|c| matches!(c.status, CaseStatus::Passed))
+            .count()
+    }
+
+    /// Number of failed cases.
+    pub fn failed(&self) -> usize {
+        self.cases
+            .iter()
+            .filter(|c| matches!(c.status, CaseStatus::Failed(_)))
+            .count()
+    }
+
+    /// Number of skipped cases.
+    pub fn skipped(&self) -> usize {
+        self.cases
+            .iter()
+            .filter(|c| matches!(c.status, CaseStatus::Skipped(_)))
+            .count()
+    }
+
+    /// Total wall-clock time across all cases in the suite.
+    pub fn total_duration(&self) -> Duration {
+        self.cases.iter().map(|c| c.duration).sum()
+    }
+}
+
+/// Accumulates recorded operations across one automation run, grouped into
+/// suites, and serializes them for CI or streaming consumption.
+///
+/// Recording is done by the caller: no high-level helper takes a recorder
+/// or records on its own, and `FillOptions`/`ClickOptions` carry none.
+/// Cases are built from operation results with
+/// [`TestCase::from_fill_result`], [`TestCase::from_click_result`],
+/// [`TestCase::from_navigation_result`], or [`TestCase::from_step_outcome`]
+/// and added with [`RunRecorder::record`].
+#[derive(Debug, Clone, Default)]
+pub struct RunRecorder {
+    suites: Vec<TestSuite>,
+}
+
+impl RunRecorder {
+    /// Create an empty recorder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new suite, making it the target of subsequent
+    /// [`RunRecorder::record`] calls.
+    pub fn begin_suite(&mut self, name: impl Into<String>) {
+        self.suites.push(TestSuite::new(name));
+    }
+
+    /// Record a case in the current suite, starting a suite named
+    /// `"default"` first if none has been started yet.
+    pub fn record(&mut self, case: TestCase) {
+        if self.suites.is_empty() {
+            self.begin_suite("default");
+        }
+        self.suites.last_mut().expect("suite just ensured").cases.push(case);
+    }
+
+    /// The recorded suites, in the order they were started.
+    pub fn suites(&self) -> &[TestSuite] {
+        &self.suites
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_case_from_fill_result_maps_skipped() {
+        let result = FillResult::skipped("existing".to_string());
+        let case = TestCase::from_fill_result("#email", &result, Duration::from_millis(5), None);
+        assert_eq!(case.status, CaseStatus::Skipped("element already had content".to_string()));
+    }
+
+    #[test]
+    fn test_case_from_fill_result_maps_failed() {
+        let result = FillResult::failed();
+        let case = TestCase::from_fill_result("#email", &result, Duration::from_millis(5), Some(3));
+        assert!(matches!(case.status, CaseStatus::Failed(_)));
+        assert_eq!(case.attempts, Some(3));
+    }
+
+    #[test]
+    fn test_case_from_fill_result_maps_passed() {
+        let result = FillResult::success("hi".to_string());
+        let case = TestCase::from_fill_result("#email", &result, Duration::from_millis(5), Some(1));
+        assert_eq!(case.status, CaseStatus::Passed);
+    }
+
+    #[test]
+    fn test_suite_counts_by_status() {
+        let mut suite = TestSuite::new("login");
+        suite.cases.push(TestCase {
+            name: "a".to_string(),
+            selector: None,
+            status: CaseStatus::Passed,
+            duration: Duration::from_millis(1),
+            attempts: None,
+        });
+        suite.cases.push(TestCase {
+            name: "b".to_string(),
+            selector: None,
+            status: CaseStatus::Failed("oops".to_string()),
+            duration: Duration::from_millis(2),
+            attempts: None,
+        });
+        suite.cases.push(TestCase {
+            name: "c".to_string(),
+            selector: None,
+            status: CaseStatus::Skipped("already set".to_string()),
+            duration: Duration::from_millis(3),
+            attempts: None,
+        });
+
+        assert_eq!(suite.passed(), 1);
+        assert_eq!(suite.failed(), 1);
+        assert_eq!(suite.skipped(), 1);
+        assert_eq!(suite.total_duration(), Duration::from_millis(6));
+    }
+
+    #[test]
+    fn run_recorder_starts_default_suite_when_needed() {
+        let mut recorder = RunRecorder::new();
+        recorder.record(TestCase {
+            name: "a".to_string(),
+            selector: None,
+            status: CaseStatus::Passed,
+            duration: Duration::from_millis(1),
+            attempts: None,
+        });
+        assert_eq!(recorder.suites().len(), 1);
+        assert_eq!(recorder.suites()[0].name, "default");
+    }
+
+    #[test]
+    fn run_recorder_groups_by_suite() {
+        let mut recorder = RunRecorder::new();
+        recorder.begin_suite("login");
+        recorder.record(TestCase {
+            name: "a".to_string(),
+            selector: None,
+            status: CaseStatus::Passed,
+            duration: Duration::from_millis(1),
+            attempts: None,
+        });
+        recorder.begin_suite("checkout");
+        recorder.record(TestCase {
+            name: "b".to_string(),
+            selector: None,
+            status: CaseStatus::Passed,
+            duration: Duration::from_millis(1),
+            attempts: None,
+        });
+
+        assert_eq!(recorder.suites().len(), 2);
+        assert_eq!(recorder.suites()[0].cases.len(), 1);
+        assert_eq!(recorder.suites()[1].cases.len(), 1);
+    }
+}
@@ -0,0 +1,75 @@
+//! Newline-delimited JSON serialization for a recorded run.
+//!
+//! One line per [`TestCase`], each carrying its suite name, so a streaming
+//! consumer (a log pipeline, a dashboard) can process results as they
+//! arrive instead of waiting for the whole run to finish.
+
+use super::{CaseStatus, RunRecorder};
+use serde_json::json;
+
+/// Serialize a recorded run as newline-delimited JSON, one object per case.
+pub fn to_ndjson(recorder: &RunRecorder) -> String {
+    let mut lines = Vec::new();
+
+    for suite in recorder.suites() {
+        for case in &suite.cases {
+            let (status, message) = match &case.status {
+                CaseStatus::Passed => ("passed", None),
+                CaseStatus::Failed(message) => ("failed", Some(message.as_str())),
+                CaseStatus::Skipped(reason) => ("skipped", Some(reason.as_str())),
+            };
+
+            let line = json!({
+                "suite": suite.name,
+                "name": case.name,
+                "selector": case.selector,
+                "status": status,
+                "message": message,
+                "duration_secs": case.duration.as_secs_f64(),
+                "attempts": case.attempts,
+            });
+            lines.push(line.to_string());
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reporting::TestCase;
+    use std::time::Duration;
+
+    #[test]
+    fn to_ndjson_emits_one_line_per_case() {
+        let mut recorder = RunRecorder::new();
+        recorder.begin_suite("login");
+        recorder.record(TestCase {
+            name: "fill #email".to_string(),
+            selector: Some("#email".to_string()),
+            status: CaseStatus::Passed,
+            duration: Duration::from_millis(250),
+            attempts: Some(1),
+        });
+        recorder.record(TestCase {
+            name: "click #submit".to_string(),
+            selector: Some("#submit".to_string()),
+            status: CaseStatus::Failed("timed out".to_string()),
+            duration: Duration::from_millis(10),
+            attempts: None,
+        });
+
+        let ndjson = to_ndjson(&recorder);
+        let lines: Vec<&str> = ndjson.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["status"], "passed");
+        assert_eq!(first["suite"], "login");
+
+        let second: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(second["status"], "failed");
+        assert_eq!(second["message"], "timed out");
+    }
+}
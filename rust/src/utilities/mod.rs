@@ -4,11 +4,16 @@
 //! - URL handling
 //! - Wait/sleep operations
 
+pub mod psl;
 pub mod url;
 pub mod wait;
 
+pub use psl::{set_psl_source, PslError};
 pub use url::{
-    get_domain, get_url, is_about_url, is_blob_url, is_data_url, parse_url, same_origin,
-    unfocus_address_bar,
+    get_domain, get_url, is_about_url, is_blob_url, is_data_url, parse_url, registrable_domain,
+    same_origin, same_site, schemeful_same_site, unfocus_address_bar,
+};
+pub use wait::{
+    evaluate, evaluate_async, safe_evaluate, wait, wait_for_function, wait_with_cancel,
+    SafeEvaluateResult, WaitForFunctionOptions, WaitForFunctionResult, WaitResult,
 };
-pub use wait::{evaluate, safe_evaluate, wait, wait_with_cancel, SafeEvaluateResult, WaitResult};
@@ -0,0 +1,246 @@
+//! Public Suffix List (PSL) support for registrable-domain extraction.
+//!
+//! Implements the standard algorithm from <https://publicsuffix.org/list/>:
+//! rules are either normal (`example.com`), wildcard (`*.ck`), or exception
+//! (`!city.kawasaki.jp`). To find the public suffix of a host, match labels
+//! from the right against every rule: an exception rule wins outright and
+//! contributes its own rule minus its leftmost label, a wildcard rule
+//! matches any single leftmost label, otherwise the longest matching normal
+//! rule wins, defaulting to the host's rightmost label if nothing matches.
+//! The registrable domain is the public suffix plus exactly one more label
+//! to its left.
+
+use std::collections::HashSet;
+use std::sync::{LazyLock, RwLock};
+use thiserror::Error;
+
+/// A representative subset of the real Public Suffix List, embedded so
+/// [`crate::utilities::registrable_domain`] and [`crate::utilities::same_site`]
+/// work offline without a network fetch. Covers the common gTLDs, a handful
+/// of ccTLDs with their real wildcard/exception rules (`*.ck`/`!www.ck`,
+/// `*.kawasaki.jp`/`!city.kawasaki.jp`), UK/AU second-level domains, and a
+/// few common privately-registered suffixes (`github.io`, `herokuapp.com`,
+/// ...).
+///
+/// For complete, up-to-date coverage, fetch the full list from
+/// <https://publicsuffix.org/list/public_suffix_list.dat> and pass its
+/// contents to [`set_psl_source`].
+const EMBEDDED_PSL: &str = r#"
+// gTLDs
+com
+org
+net
+edu
+gov
+mil
+int
+info
+biz
+name
+pro
+io
+
+// ck: real-world wildcard + exception rules
+*.ck
+!www.ck
+
+// jp: real-world wildcard + exception rules (kawasaki.jp, kobe.jp); `jp`
+// itself is a normal rule, not a wildcard, so e.g. `example.jp` is
+// registrable as a whole.
+jp
+*.kawasaki.jp
+!city.kawasaki.jp
+*.kobe.jp
+!city.kobe.jp
+
+// uk
+uk
+co.uk
+org.uk
+me.uk
+ltd.uk
+plc.uk
+net.uk
+sch.uk
+gov.uk
+ac.uk
+
+// au
+au
+com.au
+net.au
+org.au
+edu.au
+gov.au
+
+// Common privately-registered suffixes
+github.io
+gitlab.io
+herokuapp.com
+vercel.app
+netlify.app
+pages.dev
+s3.amazonaws.com
+cloudfront.net
+azurewebsites.net
+workers.dev
+"#;
+
+/// An error parsing a Public Suffix List source.
+#[derive(Debug, Error)]
+pub enum PslError {
+    /// A rule line was malformed (e.g. `!` or `*.` with nothing after it).
+    #[error("invalid PSL rule: {0:?}")]
+    InvalidRule(String),
+}
+
+#[derive(Debug, Clone, Default)]
+struct PublicSuffixList {
+    normal: HashSet<String>,
+    wildcard: HashSet<String>,
+    exception: HashSet<String>,
+}
+
+impl PublicSuffixList {
+    fn parse(source: &str) -> Result<Self, PslError> {
+        let mut list = Self::default();
+        for line in source.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+            if let Some(rule) = line.strip_prefix('!') {
+                if rule.is_empty() {
+                    return Err(PslError::InvalidRule(line.to_string()));
+                }
+                list.exception.insert(rule.to_lowercase());
+            } else if let Some(tail) = line.strip_prefix("*.") {
+                if tail.is_empty() {
+                    return Err(PslError::InvalidRule(line.to_string()));
+                }
+                list.wildcard.insert(tail.to_lowercase());
+            } else {
+                list.normal.insert(line.to_lowercase());
+            }
+        }
+        Ok(list)
+    }
+
+    /// Number of labels (from the right) making up the public suffix of
+    /// `labels`, a lowercased host split on `.`.
+    fn public_suffix_len(&self, labels: &[&str]) -> usize {
+        let n = labels.len();
+
+        // An exception rule always wins, and its public suffix is the rule
+        // minus its own leftmost label.
+        for k in 1..=n {
+            let suffix = labels[n - k..].join(".");
+            if self.exception.contains(&suffix) {
+                return k - 1;
+            }
+        }
+
+        // Otherwise the longest matching normal or wildcard rule wins.
+        let mut best = 0;
+        for k in 1..=n {
+            let suffix = labels[n - k..].join(".");
+            if self.normal.contains(&suffix) {
+                best = best.max(k);
+            }
+            if k >= 2 {
+                let tail = labels[n - k + 1..].join(".");
+                if self.wildcard.contains(&tail) {
+                    best = best.max(k);
+                }
+            }
+        }
+
+        // The implicit "*" rule applies if nothing else matched.
+        if best == 0 {
+            1
+        } else {
+            best
+        }
+    }
+}
+
+static PSL: LazyLock<RwLock<PublicSuffixList>> = LazyLock::new(|| {
+    RwLock::new(PublicSuffixList::parse(EMBEDDED_PSL).expect("embedded PSL is well-formed"))
+});
+
+/// Replace the active Public Suffix List with one parsed from `source` (in
+/// the standard `public_suffix_list.dat` format: one rule per line, `//`
+/// comments, `*.` wildcard prefix, `!` exception prefix), so
+/// [`crate::utilities::registrable_domain`]/[`crate::utilities::same_site`]
+/// can use a complete, up-to-date list instead of the embedded subset.
+pub fn set_psl_source(source: &str) -> Result<(), PslError> {
+    let parsed = PublicSuffixList::parse(source)?;
+    *PSL.write().expect("PSL registry poisoned") = parsed;
+    Ok(())
+}
+
+/// Number of labels (from the right) making up the public suffix of `host`.
+pub(crate) fn public_suffix_len(host: &str) -> usize {
+    let labels: Vec<&str> = host.split('.').collect();
+    PSL.read()
+        .expect("PSL registry poisoned")
+        .public_suffix_len(&labels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn public_suffix_len_normal_rule() {
+        assert_eq!(public_suffix_len("example.com"), 1);
+        assert_eq!(public_suffix_len("sub.example.com"), 1);
+    }
+
+    #[test]
+    fn public_suffix_len_longest_normal_rule_wins() {
+        assert_eq!(public_suffix_len("example.co.uk"), 2);
+    }
+
+    #[test]
+    fn public_suffix_len_wildcard_rule() {
+        assert_eq!(public_suffix_len("foo.ck"), 2);
+        assert_eq!(public_suffix_len("foo.bar.ck"), 2);
+    }
+
+    #[test]
+    fn public_suffix_len_exception_rule_overrides_wildcard() {
+        // "!www.ck" excepts "www.ck" from the "*.ck" wildcard rule.
+        assert_eq!(public_suffix_len("www.ck"), 1);
+    }
+
+    #[test]
+    fn public_suffix_len_jp_kawasaki_exception() {
+        assert_eq!(public_suffix_len("city.kawasaki.jp"), 2);
+        assert_eq!(public_suffix_len("foo.city.kawasaki.jp"), 2);
+        assert_eq!(public_suffix_len("other.kawasaki.jp"), 3);
+    }
+
+    #[test]
+    fn public_suffix_len_defaults_to_rightmost_label() {
+        assert_eq!(public_suffix_len("example.unknowntld"), 1);
+    }
+
+    #[test]
+    fn set_psl_source_replaces_active_list() {
+        set_psl_source("example.test\n*.wild.test\n").unwrap();
+        assert_eq!(public_suffix_len("sub.example.test"), 2);
+        assert_eq!(public_suffix_len("a.wild.test"), 3);
+
+        // Restore the embedded list so other tests in this module aren't
+        // affected by ordering (tests in a module share this process-wide
+        // registry).
+        set_psl_source(EMBEDDED_PSL).unwrap();
+    }
+
+    #[test]
+    fn set_psl_source_rejects_malformed_rules() {
+        assert!(set_psl_source("!\n").is_err());
+        assert!(set_psl_source("*.\n").is_err());
+    }
+}
@@ -3,6 +3,7 @@
 //! This module provides utilities for working with URLs.
 
 use crate::core::engine::{EngineAdapter, EngineError};
+use crate::utilities::psl::public_suffix_len;
 
 /// Get the current URL from the page.
 ///
@@ -81,6 +82,68 @@ pub fn get_domain(url_str: &str) -> Option<String> {
         .and_then(|u| u.host_str().map(String::from))
 }
 
+/// Extract the registrable domain (eTLD+1) from a URL using the Public
+/// Suffix List, e.g. `"sub.example.com"` and `"example.com"` both yield
+/// `Some("example.com")`, while `"example.co.uk"` yields
+/// `Some("example.co.uk")` since `co.uk` is the public suffix.
+///
+/// # Arguments
+///
+/// * `url_str` - The URL string
+///
+/// # Returns
+///
+/// The registrable domain, or `None` if the URL can't be parsed, has no
+/// host, or the host *is* a public suffix (e.g. `"co.uk"` itself)
+pub fn registrable_domain(url_str: &str) -> Option<String> {
+    let host = get_domain(url_str)?;
+    let labels: Vec<&str> = host.split('.').collect();
+    let suffix_len = public_suffix_len(&host);
+
+    if labels.len() <= suffix_len {
+        None
+    } else {
+        Some(labels[labels.len() - suffix_len - 1..].join("."))
+    }
+}
+
+/// Check if two URLs are same-site: they share a registrable domain,
+/// ignoring scheme. This is the classic `SameSite` cookie comparison.
+///
+/// # Arguments
+///
+/// * `url1` - First URL
+/// * `url2` - Second URL
+///
+/// # Returns
+///
+/// `true` if both URLs have the same registrable domain
+pub fn same_site(url1: &str, url2: &str) -> bool {
+    match (registrable_domain(url1), registrable_domain(url2)) {
+        (Some(d1), Some(d2)) => d1 == d2,
+        _ => false,
+    }
+}
+
+/// Check if two URLs are schemeful-same-site: same registrable domain
+/// *and* the same scheme, per the stricter definition browsers use when
+/// enforcing `SameSite=Strict`/`Lax` across scheme changes.
+///
+/// # Arguments
+///
+/// * `url1` - First URL
+/// * `url2` - Second URL
+///
+/// # Returns
+///
+/// `true` if both URLs have the same scheme and registrable domain
+pub fn schemeful_same_site(url1: &str, url2: &str) -> bool {
+    match (parse_url(url1), parse_url(url2)) {
+        (Ok(u1), Ok(u2)) => u1.scheme() == u2.scheme() && same_site(url1, url2),
+        _ => false,
+    }
+}
+
 /// Check if a URL is a data URL.
 ///
 /// # Arguments
@@ -179,6 +242,63 @@ mod tests {
         assert_eq!(get_domain("data:text/plain,hello"), None);
     }
 
+    #[test]
+    fn registrable_domain_same_for_subdomains() {
+        assert_eq!(
+            registrable_domain("https://sub.example.com/"),
+            Some("example.com".to_string())
+        );
+        assert_eq!(
+            registrable_domain("https://example.com/"),
+            Some("example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn registrable_domain_handles_multi_label_public_suffix() {
+        assert_eq!(
+            registrable_domain("https://shop.example.co.uk/"),
+            Some("example.co.uk".to_string())
+        );
+    }
+
+    #[test]
+    fn registrable_domain_none_for_bare_public_suffix() {
+        assert_eq!(registrable_domain("https://co.uk/"), None);
+        assert_eq!(registrable_domain("not a url"), None);
+    }
+
+    #[test]
+    fn same_site_true_for_subdomains() {
+        assert!(same_site(
+            "https://example.com/page1",
+            "https://sub.example.com/page2"
+        ));
+    }
+
+    #[test]
+    fn same_site_false_for_distinct_registrable_domains() {
+        // github.io is itself a public suffix, so different subdomains of
+        // it are not same-site.
+        assert!(!same_site(
+            "https://a.github.io/",
+            "https://b.github.io/"
+        ));
+        assert!(!same_site("https://example.com/", "https://other.com/"));
+    }
+
+    #[test]
+    fn schemeful_same_site_requires_matching_scheme() {
+        assert!(schemeful_same_site(
+            "https://example.com/",
+            "https://sub.example.com/"
+        ));
+        assert!(!schemeful_same_site(
+            "https://example.com/",
+            "http://example.com/"
+        ));
+    }
+
     #[test]
     fn is_data_url_true() {
         assert!(is_data_url("data:text/plain,hello"));
@@ -4,7 +4,7 @@
 //! with optional abort signal support.
 
 use crate::core::engine::{EngineAdapter, EngineError};
-use crate::core::navigation::is_navigation_error;
+use crate::core::navigation::{is_dialog_error, is_navigation_error};
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
 
@@ -98,13 +98,40 @@ pub async fn evaluate(
     adapter.evaluate(script).await
 }
 
-/// Safe evaluate that catches navigation errors and returns a default value.
+/// Evaluate an asynchronous script, resolving when the page invokes the
+/// implicit completion callback (or the `Promise` it returns settles)
+/// instead of returning as soon as the script itself runs to completion.
+///
+/// # Arguments
+///
+/// * `adapter` - The engine adapter to use
+/// * `script` - The async JavaScript to evaluate
+/// * `timeout` - How long to wait for the page to invoke the callback
+///
+/// # Returns
+///
+/// The value the callback (or settled `Promise`) was invoked with
+pub async fn evaluate_async(
+    adapter: &dyn EngineAdapter,
+    script: &str,
+    timeout: Duration,
+) -> Result<serde_json::Value, EngineError> {
+    adapter.evaluate_async(script, timeout.as_millis() as u64).await
+}
+
+/// Safe evaluate that catches navigation errors and pending-dialog errors,
+/// returning a default value instead of propagating either.
+///
+/// A native JavaScript dialog (`alert`/`confirm`/`prompt`/`beforeunload`)
+/// blocks most WebDriver commands until it's accepted or dismissed, the
+/// same way a mid-navigation page does, so it's treated the same way here:
+/// swallowed rather than surfaced as an evaluation failure.
 ///
 /// # Arguments
 ///
 /// * `adapter` - The engine adapter to use
 /// * `script` - The JavaScript to evaluate
-/// * `default` - Default value to return on navigation error
+/// * `default` - Default value to return on navigation or dialog error
 ///
 /// # Returns
 ///
@@ -119,16 +146,25 @@ pub async fn safe_evaluate(
             success: true,
             value,
             navigation_error: false,
+            dialog_pending: false,
         },
         Err(e) if is_navigation_error(&e.to_string()) => SafeEvaluateResult {
             success: false,
             value: default,
             navigation_error: true,
+            dialog_pending: false,
+        },
+        Err(e) if is_dialog_error(&e.to_string()) => SafeEvaluateResult {
+            success: false,
+            value: default,
+            navigation_error: false,
+            dialog_pending: true,
         },
         Err(_) => SafeEvaluateResult {
             success: false,
             value: default,
             navigation_error: false,
+            dialog_pending: false,
         },
     }
 }
@@ -142,6 +178,110 @@ pub struct SafeEvaluateResult {
     pub value: serde_json::Value,
     /// Whether a navigation error occurred.
     pub navigation_error: bool,
+    /// Whether an open JavaScript dialog blocked the evaluation.
+    pub dialog_pending: bool,
+}
+
+/// Options for [`wait_for_function`].
+#[derive(Debug, Clone, Copy)]
+pub struct WaitForFunctionOptions {
+    /// How often to re-evaluate the predicate.
+    pub interval: Duration,
+    /// Overall deadline across every poll.
+    pub timeout: Duration,
+}
+
+impl Default for WaitForFunctionOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_millis(100),
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Result of [`wait_for_function`].
+#[derive(Debug, Clone)]
+pub struct WaitForFunctionResult {
+    /// The underlying completed/aborted outcome.
+    pub result: WaitResult,
+    /// How many times the predicate was evaluated.
+    pub polls: u32,
+}
+
+/// Whether a JSON value is "truthy" by JavaScript's rules: everything is
+/// truthy except `null`, `false`, `0`/`NaN`, and `""`.
+fn is_json_truthy(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Null => false,
+        serde_json::Value::Bool(b) => *b,
+        serde_json::Value::Number(n) => n.as_f64().is_some_and(|f| f != 0.0 && !f.is_nan()),
+        serde_json::Value::String(s) => !s.is_empty(),
+        serde_json::Value::Array(_) | serde_json::Value::Object(_) => true,
+    }
+}
+
+/// Poll `predicate_js` via [`safe_evaluate`] until it evaluates JSON-truthy,
+/// the overall deadline elapses, or `cancel_token` fires.
+///
+/// Navigation (and dialog) errors are treated as "retry": the page is
+/// likely mid-reload, so they're swallowed the same way [`safe_evaluate`]
+/// already swallows them, rather than aborting the wait outright.
+///
+/// # Arguments
+///
+/// * `adapter` - The engine adapter to use
+/// * `predicate_js` - JavaScript that evaluates to a truthy value once the
+///   awaited condition holds
+/// * `options` - Poll interval and overall deadline
+/// * `cancel_token` - Cancellation token to abort the wait early
+///
+/// # Returns
+///
+/// The wait outcome, plus how many polls it took
+pub async fn wait_for_function(
+    adapter: &dyn EngineAdapter,
+    predicate_js: &str,
+    options: &WaitForFunctionOptions,
+    cancel_token: &CancellationToken,
+) -> WaitForFunctionResult {
+    let deadline = tokio::time::Instant::now() + options.timeout;
+    let mut polls: u32 = 0;
+
+    loop {
+        if cancel_token.is_cancelled() {
+            return WaitForFunctionResult {
+                result: WaitResult::aborted(),
+                polls,
+            };
+        }
+
+        polls += 1;
+        let outcome = safe_evaluate(adapter, predicate_js, serde_json::Value::Bool(false)).await;
+        if outcome.success && is_json_truthy(&outcome.value) {
+            return WaitForFunctionResult {
+                result: WaitResult::completed(),
+                polls,
+            };
+        }
+
+        if tokio::time::Instant::now() >= deadline {
+            return WaitForFunctionResult {
+                result: WaitResult::aborted(),
+                polls,
+            };
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(options.interval) => {}
+            _ = cancel_token.cancelled() => {
+                return WaitForFunctionResult {
+                    result: WaitResult::aborted(),
+                    polls,
+                };
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -237,6 +377,7 @@ mod tests {
             success: true,
             value: serde_json::json!(42),
             navigation_error: false,
+            dialog_pending: false,
         };
         assert!(result.success);
         assert!(!result.navigation_error);
@@ -249,9 +390,200 @@ mod tests {
             success: false,
             value: serde_json::Value::Null,
             navigation_error: true,
+            dialog_pending: false,
         };
         assert!(!result.success);
         assert!(result.navigation_error);
         assert!(result.value.is_null());
     }
+
+    #[test]
+    fn safe_evaluate_result_dialog_pending() {
+        let result = SafeEvaluateResult {
+            success: false,
+            value: serde_json::Value::Null,
+            navigation_error: false,
+            dialog_pending: true,
+        };
+        assert!(!result.success);
+        assert!(result.dialog_pending);
+        assert!(result.value.is_null());
+    }
+
+    #[test]
+    fn is_json_truthy_matches_js_semantics() {
+        assert!(!is_json_truthy(&serde_json::Value::Null));
+        assert!(!is_json_truthy(&serde_json::json!(false)));
+        assert!(!is_json_truthy(&serde_json::json!(0)));
+        assert!(!is_json_truthy(&serde_json::json!("")));
+        assert!(is_json_truthy(&serde_json::json!(true)));
+        assert!(is_json_truthy(&serde_json::json!(1)));
+        assert!(is_json_truthy(&serde_json::json!("0")));
+        assert!(is_json_truthy(&serde_json::json!([])));
+        assert!(is_json_truthy(&serde_json::json!({})));
+    }
+
+    /// Minimal adapter whose `evaluate` turns truthy once it has been
+    /// called `truthy_after` times, used to exercise [`wait_for_function`]
+    /// without a real browser backend.
+    struct CountingAdapter {
+        calls: std::sync::atomic::AtomicU32,
+        truthy_after: u32,
+    }
+
+    #[async_trait::async_trait]
+    impl EngineAdapter for CountingAdapter {
+        fn engine_type(&self) -> crate::core::engine::EngineType {
+            crate::core::engine::EngineType::Fantoccini
+        }
+
+        async fn url(&self) -> Result<String, EngineError> {
+            unimplemented!()
+        }
+
+        async fn goto(&self, _url: &str) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn query_selector(
+            &self,
+            _selector: &str,
+        ) -> Result<Option<crate::core::engine::ElementInfo>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn query_selector_all(
+            &self,
+            _selector: &str,
+        ) -> Result<Vec<crate::core::engine::ElementInfo>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn count(&self, _selector: &str) -> Result<usize, EngineError> {
+            unimplemented!()
+        }
+
+        async fn click(&self, _selector: &str) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn fill(&self, _selector: &str, _text: &str) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn type_text(&self, _selector: &str, _text: &str) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn text_content(&self, _selector: &str) -> Result<Option<String>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn input_value(&self, _selector: &str) -> Result<Option<String>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn get_attribute(
+            &self,
+            _selector: &str,
+            _attribute: &str,
+        ) -> Result<Option<String>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn is_visible(&self, _selector: &str) -> Result<bool, EngineError> {
+            unimplemented!()
+        }
+
+        async fn is_enabled(&self, _selector: &str) -> Result<bool, EngineError> {
+            unimplemented!()
+        }
+
+        async fn wait_for_selector(
+            &self,
+            _selector: &str,
+            _timeout_ms: u64,
+        ) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn scroll_into_view(
+            &self,
+            _selector: &str,
+            _alignment: crate::core::engine::ScrollAlignment,
+        ) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn evaluate(&self, _script: &str) -> Result<serde_json::Value, EngineError> {
+            let calls = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+            Ok(serde_json::json!(calls >= self.truthy_after))
+        }
+
+        async fn screenshot(&self) -> Result<Vec<u8>, EngineError> {
+            unimplemented!()
+        }
+
+        async fn bring_to_front(&self) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+
+        async fn wait_for_navigation(&self, _timeout_ms: u64) -> Result<(), EngineError> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn wait_for_function_completes_once_predicate_turns_truthy() {
+        let adapter = CountingAdapter {
+            calls: std::sync::atomic::AtomicU32::new(0),
+            truthy_after: 3,
+        };
+        let token = CancellationToken::new();
+        let options = WaitForFunctionOptions {
+            interval: Duration::from_millis(1),
+            timeout: Duration::from_secs(5),
+        };
+
+        let result = wait_for_function(&adapter, "return true", &options, &token).await;
+
+        assert!(result.result.completed);
+        assert!(!result.result.aborted);
+        assert_eq!(result.polls, 3);
+    }
+
+    #[tokio::test]
+    async fn wait_for_function_times_out_when_predicate_stays_falsy() {
+        let adapter = CountingAdapter {
+            calls: std::sync::atomic::AtomicU32::new(0),
+            truthy_after: u32::MAX,
+        };
+        let token = CancellationToken::new();
+        let options = WaitForFunctionOptions {
+            interval: Duration::from_millis(1),
+            timeout: Duration::from_millis(20),
+        };
+
+        let result = wait_for_function(&adapter, "return false", &options, &token).await;
+
+        assert!(!result.result.completed);
+        assert!(result.result.aborted);
+        assert!(result.polls > 0);
+    }
+
+    #[tokio::test]
+    async fn wait_for_function_aborts_immediately_when_already_cancelled() {
+        let adapter = CountingAdapter {
+            calls: std::sync::atomic::AtomicU32::new(0),
+            truthy_after: u32::MAX,
+        };
+        let token = CancellationToken::new();
+        token.cancel();
+        let options = WaitForFunctionOptions::default();
+
+        let result = wait_for_function(&adapter, "return false", &options, &token).await;
+
+        assert!(result.result.aborted);
+        assert_eq!(result.polls, 0);
+    }
 }